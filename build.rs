@@ -0,0 +1,231 @@
+//! Generates the registry of daily solutions and the tests for their sample inputs.
+//!
+//! Every file `src/days/YYYY/DD.rs` is included as the module `yYYYY::dayDD`, and its `Problem`
+//! type is registered as the puzzle released on `YYYY-12-DD`. If the file also has a line
+//! starting with `register!(`, the reference solution and input generator it declares are added
+//! to the day's entry.
+//!
+//! Every sample input `inputs/<date>-sample*.txt` with a sidecar `inputs/<date>-sample*.toml`
+//! gets a test for each part listed in the sidecar:
+//!
+//! ```toml
+//! part1 = 40
+//! part2 = 25272
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::DocumentMut;
+
+fn is_date(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, &b)| match i {
+            4 | 7 => b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+fn is_number(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parses the two-digit day of a file name, failing the build if it isn't a puzzle day.
+fn parse_day(day: &str, path: &Path) -> u8 {
+    match day.parse() {
+        Ok(day @ 1..=25) => day,
+        _ => panic!("{}: {day} is not a day from 01 to 25", path.display()),
+    }
+}
+
+/// Returns the names of the subdirectories of `dir` which are years, sorted.
+fn year_dirs(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut years: Vec<String> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| Some(path.file_name()?.to_str()?.to_owned()))
+        .filter(|name| is_number(name, 4))
+        .collect();
+    years.sort();
+    years
+}
+
+/// Writes `yYYYY::dayDD` modules, with `body` producing the contents of each day's module.
+fn write_modules<'a>(
+    out: &mut String,
+    days: impl IntoIterator<Item = (&'a str, &'a str)>,
+    mut body: impl FnMut(&mut String, &str, &str),
+) {
+    let mut years: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (year, day) in days {
+        years.entry(year).or_default().push(day);
+    }
+    for (year, days) in years {
+        writeln!(out, "mod y{year} {{").unwrap();
+        for day in days {
+            body(out, year, day);
+        }
+        writeln!(out, "}}").unwrap();
+    }
+}
+
+/// Returns the stems of the files in `dir` with the given extension, sorted.
+fn file_stems(dir: &Path, extension: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut stems: Vec<String> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_owned()))
+        .collect();
+    stems.sort();
+    stems
+}
+
+fn generate_registry(days_dir: &Path) -> String {
+    let mut days: Vec<(String, String)> = Vec::new();
+    for year in year_dirs(days_dir) {
+        let year_dir = days_dir.join(&year);
+        println!("cargo::rerun-if-changed={}", year_dir.display());
+        for day in file_stems(&year_dir, "rs") {
+            if is_number(&day, 2) {
+                days.push((year.clone(), day));
+            }
+        }
+    }
+
+    let mut out = String::new();
+    write_modules(
+        &mut out,
+        days.iter().map(|(year, day)| (year.as_str(), day.as_str())),
+        |out, year, day| {
+            let path = days_dir.join(year).join(format!("{day}.rs"));
+            writeln!(out, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(out, "    pub(super) mod day{day};").unwrap();
+        },
+    );
+    writeln!(out, "\n/// All registered solutions, sorted by date.").unwrap();
+    writeln!(out, "pub static ENTRIES: &[Entry] = &[").unwrap();
+    for (year, day) in &days {
+        // The registry depends on what each file declares, not just on which files exist.
+        let path = days_dir.join(year).join(format!("{day}.rs"));
+        println!("cargo::rerun-if-changed={}", path.display());
+        let source = fs::read_to_string(&path).unwrap();
+        let module = format!("y{year}::day{day}");
+        let id = format!("PuzzleId::new({year}, {})", parse_day(day, &path));
+        let entry = format!("Entry::new::<{module}::Problem>({id})");
+        if source
+            .lines()
+            .any(|line| line.trim_start().starts_with("register!("))
+        {
+            writeln!(out, "    {module}::register({entry}),").unwrap();
+        } else {
+            writeln!(out, "    {entry},").unwrap();
+        }
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+/// Splits a sample's file stem into its date and the suffix after `-sample`.
+fn parse_sample_stem(stem: &str) -> Option<(&str, &str)> {
+    let (date, rest) = stem.split_at_checked(10)?;
+    let suffix = rest.strip_prefix("-sample")?;
+    let valid = is_date(date)
+        && suffix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then_some((date, suffix))
+}
+
+fn generate_sample_tests(inputs_dir: &Path) -> String {
+    let mut tests: BTreeMap<(&str, &str), String> = BTreeMap::new();
+    let stems = file_stems(inputs_dir, "txt");
+    for stem in &stems {
+        let Some((date, suffix)) = parse_sample_stem(stem) else {
+            continue;
+        };
+        let sidecar: PathBuf = inputs_dir.join(format!("{stem}.toml"));
+        let Ok(expected) = fs::read_to_string(&sidecar) else {
+            continue;
+        };
+        println!("cargo::rerun-if-changed={}", sidecar.display());
+        let expected: DocumentMut = expected
+            .parse()
+            .unwrap_or_else(|err| panic!("{}: {err}", sidecar.display()));
+        let sample = inputs_dir.join(format!("{stem}.txt"));
+        let (year, day) = (&date[..4], &date[8..]);
+        let out = tests.entry((year, day)).or_default();
+        for part in 1..=2 {
+            let Some(value) = expected.get(&format!("part{part}")) else {
+                continue;
+            };
+            let expected = match (value.as_integer(), value.as_str()) {
+                (Some(n), _) => n.to_string(),
+                (None, Some(s)) => s.to_owned(),
+                _ => panic!("{}: answers must be integers or strings", sidecar.display()),
+            };
+            writeln!(
+                out,
+                "        #[test]\n        fn sample{suffix}_part{part}() {{\n            \
+                 crate::days::samples::check_sample(\n                \
+                 PuzzleId::new({year}, {}),\n                \
+                 include_str!({:?}),\n                {part},\n                {expected:?},\n            \
+                 );\n        }}",
+                parse_day(day, &sample),
+                sample.display().to_string(),
+            )
+            .unwrap();
+        }
+    }
+
+    let mut out = String::new();
+    write_modules(&mut out, tests.keys().copied(), |out, year, day| {
+        let tests = &tests[&(year, day)];
+        writeln!(
+            out,
+            "    mod day{day} {{\n        use crate::PuzzleId;\n\n{tests}    }}"
+        )
+        .unwrap();
+    });
+    out
+}
+
+/// Writes `contents` to `path` unless it already holds them, to avoid needless rebuilds.
+fn write_if_changed(path: &Path, contents: &str) {
+    if fs::read_to_string(path).is_ok_and(|old| old == contents) {
+        return;
+    }
+    fs::write(path, contents).unwrap();
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let days_dir = manifest_dir.join("src/days");
+    println!("cargo::rerun-if-changed={}", days_dir.display());
+    write_if_changed(&out_dir.join("days.rs"), &generate_registry(&days_dir));
+
+    // Inputs aren't committed, so this directory may not exist yet. Cargo then reruns this
+    // script on every build, which is cheap since the output is only rewritten when it changes.
+    let inputs_dir = manifest_dir.join("inputs");
+    println!("cargo::rerun-if-changed={}", inputs_dir.display());
+    let samples = generate_sample_tests(&inputs_dir);
+    // Without samples, as in a fresh checkout, the module running them is left out entirely.
+    println!("cargo::rustc-check-cfg=cfg(has_samples)");
+    if !samples.is_empty() {
+        println!("cargo::rustc-cfg=has_samples");
+    }
+    write_if_changed(&out_dir.join("samples.rs"), &samples);
+}
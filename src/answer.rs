@@ -0,0 +1,144 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An integer, which is what most puzzles ask for.
+    Int(i128),
+    /// A single-line string, such as a code or a list of coordinates.
+    Str(String),
+    /// Multi-line text, such as letters drawn in a grid.
+    Text(String),
+}
+
+impl Answer {
+    /// Returns `true` if the answer must be displayed on its own lines.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Text(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Str(s) | Answer::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Parses an answer as written in a file or on the command line, following the same rules as
+/// converting a [`String`].
+impl FromStr for Answer {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(String::from(s).into())
+    }
+}
+
+/// Returns the integer `s` spells, if it is written the way the integer would be displayed.
+/// Text like `0123` or `+5` stays text, so that it compares equal to itself after a round trip.
+fn canonical_int(s: &str) -> Option<i128> {
+    s.parse().ok().filter(|n: &i128| n.to_string() == s)
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Answer {
+        Answer::Int(n as i128)
+    }
+}
+
+/// Counts too large for an [`Answer::Int`] are kept as their digits, which is also how they are
+/// parsed back.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        i128::try_from(n).map_or_else(|_| Answer::Str(n.to_string()), Answer::Int)
+    }
+}
+
+/// Text containing newlines becomes [`Answer::Text`], integers written in their usual form
+/// become [`Answer::Int`], and anything else becomes [`Answer::Str`].
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        if s.contains('\n') {
+            Answer::Text(s)
+        } else if let Some(n) = canonical_int(&s) {
+            Answer::Int(n)
+        } else {
+            Answer::Str(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        String::from(s).into()
+    }
+}
+
+/// Allows [`Infallible`] to be used as the output type of parts which don't exist.
+impl From<Infallible> for Answer {
+    fn from(never: Infallible) -> Answer {
+        match never {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_round_trips() {
+        for answer in [
+            Answer::Int(-12),
+            Answer::Str("EFHZ".into()),
+            Answer::Text("#..#\n####".into()),
+        ] {
+            assert_eq!(answer, answer.to_string().parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn digit_strings_match_integers() {
+        assert_eq!(Answer::Int(1234), Answer::from("1234"));
+        assert_eq!(Answer::from("1234".to_string()), "1234".parse().unwrap());
+        assert_eq!(Answer::Str("0123".into()), "0123".parse().unwrap());
+        assert_eq!(Answer::Str("+5".into()), Answer::from("+5"));
+    }
+
+    #[test]
+    fn integer_types_compare_equal() {
+        assert_eq!(Answer::from(42u8), Answer::from(42usize));
+        assert_eq!(Answer::from(42i64), "42".parse().unwrap());
+        assert_eq!(Answer::from(42i64), Answer::from(42u128));
+        assert_eq!(
+            Answer::from(u128::MAX),
+            u128::MAX.to_string().parse().unwrap()
+        );
+    }
+}
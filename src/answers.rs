@@ -0,0 +1,145 @@
+//! The expected answers file, `answers.toml`.
+//!
+//! Each puzzle gets a table keyed by its date, holding the accepted answer for each part:
+//!
+//! ```toml
+//! [2025-12-01]
+//! part1 = 1234
+//! part2 = 5678
+//! ```
+//!
+//! The file is edited in place, so comments and formatting survive recording new answers.
+
+use std::{fs, io, path::Path};
+
+use toml_edit::{DocumentMut, Item, Table, Value};
+
+use crate::{Answer, PuzzleId};
+
+/// Default location of the answers file, relative to the repository root.
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// Accepted answers to puzzles.
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    doc: DocumentMut,
+}
+
+fn part_key(part: u8) -> String {
+    format!("part{part}")
+}
+
+impl Answers {
+    /// Parses an answers file.
+    pub fn parse(s: &str) -> io::Result<Answers> {
+        let doc = s
+            .parse::<DocumentMut>()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Ok(Answers { doc })
+    }
+
+    /// Reads the answers file at `path`. A missing file is treated as an empty one.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Answers> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(s) => Answers::parse(&s)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the answers back to `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.doc.to_string())
+    }
+
+    /// Returns the accepted answer for the given part, if one has been recorded.
+    pub fn get(&self, id: PuzzleId, part: u8) -> Option<Answer> {
+        let value = self.doc.get(&id.to_string())?.get(part_key(part))?;
+        if let Some(n) = value.as_integer() {
+            Some(Answer::Int(n.into()))
+        } else {
+            value.as_str().map(Answer::from)
+        }
+    }
+
+    /// Returns the puzzles with a table in the file, whether or not it holds any answers.
+    pub fn puzzles(&self) -> impl Iterator<Item = PuzzleId> {
+        self.doc.iter().filter_map(|(key, _)| key.parse().ok())
+    }
+
+    /// Records the accepted answer for the given part, replacing any previous one.
+    pub fn set(&mut self, id: PuzzleId, part: u8, answer: &Answer) {
+        let value = match answer {
+            Answer::Int(n) => match i64::try_from(*n) {
+                Ok(n) => Value::from(n),
+                Err(_) => Value::from(n.to_string()),
+            },
+            Answer::Str(s) | Answer::Text(s) => Value::from(s.as_str()),
+        };
+        let table = self
+            .doc
+            .entry(&id.to_string())
+            .or_insert_with(|| Item::Table(Table::new()));
+        table[part_key(part).as_str()] = Item::Value(value);
+        self.doc.sort_values();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn set_preserves_comments() {
+        let mut answers =
+            Answers::parse("# Accepted answers\n\n[2025-12-01]\npart1 = 3\n").unwrap();
+        let (day1, day2) = (PuzzleId::new(2025, 1), PuzzleId::new(2025, 2));
+        answers.set(day1, 2, &Answer::Int(6));
+        answers.set(day2, 1, &Answer::Str("EFHZ".into()));
+        assert_eq!(
+            "# Accepted answers\n\n[2025-12-01]\npart1 = 3\npart2 = 6\n\n[2025-12-02]\npart1 = \"EFHZ\"\n",
+            answers.doc.to_string()
+        );
+        assert_eq!(Some(Answer::Int(6)), answers.get(day1, 2));
+        assert_eq!(Some(Answer::Str("EFHZ".into())), answers.get(day2, 1));
+        assert_eq!(None, answers.get(day2, 2));
+        assert_eq!(vec![day1, day2], answers.puzzles().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn string_answers_round_trip() {
+        let mut answers = Answers::default();
+        let id = PuzzleId::new(2025, 3);
+        for answer in ["1234", "0123", "EFHZ"].map(Answer::from) {
+            answers.set(id, 1, &answer);
+            assert_eq!(Some(answer), answers.get(id, 1));
+        }
+    }
+
+    /// Recording saves the file and checking loads it again, so go through the file too.
+    #[test]
+    fn recorded_digit_strings_match_after_reload() {
+        let path = std::env::temp_dir().join(format!("answers-{}.toml", std::process::id()));
+        let id = PuzzleId::new(2025, 4);
+        let mut answers = Answers::load(&path).unwrap();
+        answers.set(id, 1, &Answer::from("0042".to_string()));
+        answers.set(id, 2, &Answer::from("42".to_string()));
+        answers.save(&path).unwrap();
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Some(Answer::from("0042")), loaded.get(id, 1));
+        assert_eq!(Some(Answer::from(42u8)), loaded.get(id, 2));
+    }
+
+    #[test]
+    fn large_integers_round_trip() {
+        let mut answers = Answers::default();
+        let big = Answer::Int(i128::from(i64::MAX) + 1);
+        let id = PuzzleId::new(2025, 11);
+        answers.set(id, 2, &big);
+        assert_eq!(Some(big), answers.get(id, 2));
+    }
+}
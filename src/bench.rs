@@ -0,0 +1,198 @@
+//! Benchmarking of solutions, with a JSON history for spotting regressions.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{PuzzleId, Result, days::Entry};
+
+/// Default location of the benchmark history, relative to the repository root.
+pub const DEFAULT_HISTORY_PATH: &str = "bench_history.json";
+
+/// Summary statistics of a set of timings, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub stddev: u64,
+}
+
+impl Stats {
+    /// Computes statistics of the given timings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is empty.
+    pub fn new(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to compute statistics of");
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+        let n = nanos.len();
+        let median = if n % 2 == 1 {
+            nanos[n / 2]
+        } else {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+        };
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        Stats {
+            min: nanos[0] as u64,
+            median: median.round() as u64,
+            mean: mean.round() as u64,
+            stddev: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+/// Benchmark results for one day, keyed by phase (`parse`, `part1`, `part2`).
+pub type DayBench = BTreeMap<String, Stats>;
+
+/// Runs the solution `runs` times on the given input, after one warm-up run, and returns the
+/// statistics of each phase. Parts which aren't implemented are left out.
+///
+/// # Panics
+///
+/// Panics if `runs` is zero.
+pub fn bench(entry: &Entry, input: &str, runs: usize) -> Result<DayBench> {
+    assert!(runs > 0, "must benchmark at least one run");
+    // Normalize once up front, so that any warnings aren't repeated for every run.
+    let input = &entry.normalize(input);
+    let mut implemented = Vec::new();
+    for part in entry.run(input, None)?.parts {
+        match part.answer {
+            Err(err) if err.is_not_implemented() => {}
+            answer => {
+                answer?;
+                implemented.push(part.part);
+            }
+        }
+    }
+    let mut samples: BTreeMap<String, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..runs {
+        let run = entry.run(input, None)?;
+        samples
+            .entry("parse".to_string())
+            .or_default()
+            .push(run.parse_time);
+        for part in run.parts {
+            if !implemented.contains(&part.part) {
+                continue;
+            }
+            samples
+                .entry(format!("part{}", part.part))
+                .or_default()
+                .push(part.time);
+        }
+    }
+    Ok(samples
+        .into_iter()
+        .map(|(phase, samples)| (phase, Stats::new(&samples)))
+        .collect())
+}
+
+/// One invocation of the benchmark harness.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Seconds since the Unix epoch at which the run started.
+    pub timestamp: u64,
+    /// Number of timed runs of each solution.
+    pub runs: usize,
+    /// Results keyed by date.
+    pub days: BTreeMap<String, DayBench>,
+}
+
+impl BenchRun {
+    pub fn new(runs: usize) -> BenchRun {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        BenchRun {
+            timestamp,
+            runs,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+/// Reads the benchmark history at `path`, oldest run first. A missing file is treated as an
+/// empty history.
+pub fn load_history(path: impl AsRef<Path>) -> io::Result<Vec<BenchRun>> {
+    match fs::read_to_string(path) {
+        Ok(s) => Ok(serde_json::from_str(&s)?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Writes the benchmark history to `path`.
+pub fn save_history(path: impl AsRef<Path>, history: &[BenchRun]) -> io::Result<()> {
+    let mut json = serde_json::to_string_pretty(history)?;
+    json.push('\n');
+    fs::write(path, json)
+}
+
+/// Returns the most recent result for the given day and phase in `history`.
+pub fn previous<'a>(history: &'a [BenchRun], id: PuzzleId, phase: &str) -> Option<&'a Stats> {
+    let date = id.to_string();
+    history
+        .iter()
+        .rev()
+        .find_map(|run| run.days.get(&date)?.get(phase))
+}
+
+/// Returns the relative change of the median from `old` to `new`, as a percentage.
+pub fn change_percent(old: &Stats, new: &Stats) -> f64 {
+    (new.median as f64 - old.median as f64) / old.median.max(1) as f64 * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        assert_eq!(
+            Stats {
+                min: 1,
+                median: 3, // 2.5, rounded
+                mean: 3,
+                stddev: 1,
+            },
+            Stats::new(&samples)
+        );
+    }
+
+    #[test]
+    fn previous_finds_latest_run_with_phase() {
+        let stats = |median| Stats {
+            min: 0,
+            median,
+            mean: 0,
+            stddev: 0,
+        };
+        let mut old = BenchRun::new(1);
+        old.days.insert(
+            "2025-12-01".into(),
+            DayBench::from([("part2".into(), stats(10))]),
+        );
+        let mut new = BenchRun::new(1);
+        new.days.insert(
+            "2025-12-01".into(),
+            DayBench::from([("part1".into(), stats(20))]),
+        );
+        let history = [old, new];
+        let (day1, day2) = (PuzzleId::new(2025, 1), PuzzleId::new(2025, 2));
+        assert_eq!(Some(&stats(10)), previous(&history, day1, "part2"));
+        assert_eq!(Some(&stats(20)), previous(&history, day1, "part1"));
+        assert_eq!(None, previous(&history, day2, "part1"));
+        assert_eq!(100.0, change_percent(&stats(10), &stats(20)));
+    }
+}
@@ -0,0 +1,112 @@
+//! Benchmarks solutions and compares them against the previous benchmark run.
+
+use std::time::Duration;
+
+use advent_of_code::{
+    Selection,
+    bench::{self, BenchRun, Stats},
+    days::{self, Entry},
+};
+
+use crate::{default_input_path, read_input, select_all};
+
+#[derive(Debug, clap::Args)]
+pub struct BenchArgs {
+    /// Puzzles to benchmark: years (`2024`), dates (`2025-12-01`) or ranges of days
+    /// (`2025-12-01..05`). Benchmarks every day with an input if none are given.
+    puzzles: Vec<Selection>,
+
+    /// Number of timed runs of each solution.
+    #[arg(short = 'n', long, default_value_t = 100)]
+    runs: usize,
+
+    /// Flag phases whose median time grew by more than this percentage.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// JSON file holding the history of benchmark runs.
+    #[arg(long, default_value = bench::DEFAULT_HISTORY_PATH)]
+    history: String,
+
+    /// Don't append this run to the history.
+    #[arg(long)]
+    no_save: bool,
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+/// Runs the benchmarks. Returns `Ok(false)` if any phase regressed beyond the threshold.
+pub fn bench(args: &BenchArgs) -> Result<bool, String> {
+    if args.runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+    let entries: Vec<&Entry> = if args.puzzles.is_empty() {
+        days::ENTRIES
+            .iter()
+            .filter(|entry| default_input_path(entry).exists())
+            .collect()
+    } else {
+        select_all(&args.puzzles)?
+    };
+    let mut history =
+        bench::load_history(&args.history).map_err(|err| format!("{}: {err}", args.history))?;
+
+    let mut current = BenchRun::new(args.runs);
+    let mut regressions = 0;
+    println!(
+        "{:<10}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "date", "phase", "min", "median", "mean", "stddev", "change"
+    );
+    for entry in entries {
+        let path = default_input_path(entry);
+        let input = read_input(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let day =
+            bench::bench(entry, &input, args.runs).map_err(|err| format!("{}: {err}", entry.id))?;
+        for (phase, stats) in &day {
+            let Stats {
+                min,
+                median,
+                mean,
+                stddev,
+            } = *stats;
+            let change = match bench::previous(&history, entry.id, phase) {
+                Some(previous) => {
+                    let change = bench::change_percent(previous, stats);
+                    let flag = if change > args.threshold {
+                        regressions += 1;
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    };
+                    format!("{change:>+7.1}%{flag}")
+                }
+                None => format!("{:>8}", "new"),
+            };
+            println!(
+                "{:<10}  {phase:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {change}",
+                entry.id,
+                format_nanos(min),
+                format_nanos(median),
+                format_nanos(mean),
+                format_nanos(stddev),
+            );
+        }
+        current.days.insert(entry.id.to_string(), day);
+    }
+
+    if !args.no_save {
+        history.push(current);
+        bench::save_history(&args.history, &history)
+            .map_err(|err| format!("{}: {err}", args.history))?;
+    }
+    if regressions > 0 {
+        println!();
+        println!(
+            "{regressions} phase(s) slowed down by more than {}%",
+            args.threshold
+        );
+    }
+    Ok(regressions == 0)
+}
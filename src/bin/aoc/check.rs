@@ -0,0 +1,83 @@
+//! Verifies solutions against the answers file.
+
+use std::io;
+
+use advent_of_code::{
+    Selection,
+    answers::{self, Answers},
+};
+
+use crate::{
+    default_input_path,
+    parallel::{self, Outcome},
+    read_input, select_entries,
+};
+
+/// Runs the selected days, or every day, on its real input and reports whether each part's answer
+/// matches the accepted one.
+///
+/// Returns `Ok(false)` if any part failed, panicked or gave the wrong answer. Parts without an accepted
+/// answer are reported as missing, and parts which aren't implemented yet are reported as
+/// such; neither counts as a failure.
+pub fn check(selection: Option<&Selection>) -> Result<bool, String> {
+    let entries = select_entries(selection)?;
+    let answers = Answers::load(answers::DEFAULT_PATH).map_err(|err| err.to_string())?;
+
+    let (mut passed, mut failed, mut missing, mut unimplemented) = (0, 0, 0, 0);
+    for entry in entries {
+        let path = default_input_path(entry);
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                println!("{}: no input at {}", entry.id, path.display());
+                missing += entry.parts as usize;
+                continue;
+            }
+            Err(err) => return Err(format!("{}: {err}", path.display())),
+        };
+        let run = match parallel::run_isolated(entry, &input, None) {
+            Outcome::Ran(Ok(run)) => run,
+            Outcome::Ran(Err(err)) => {
+                println!("{}: FAIL ({err})", entry.id);
+                failed += entry.parts as usize;
+                continue;
+            }
+            Outcome::Panicked(message) => {
+                println!("{}: FAIL (panicked: {message})", entry.id);
+                failed += entry.parts as usize;
+                continue;
+            }
+        };
+        for part in run.parts {
+            let status = match (part.answer, answers.get(entry.id, part.part)) {
+                (Err(err), _) if err.is_not_implemented() => {
+                    unimplemented += 1;
+                    "not implemented".to_string()
+                }
+                (Err(err), _) => {
+                    failed += 1;
+                    format!("FAIL ({err})")
+                }
+                (Ok(actual), None) => {
+                    missing += 1;
+                    format!("missing (got {actual})")
+                }
+                (Ok(actual), Some(expected)) if actual == expected => {
+                    passed += 1;
+                    "pass".to_string()
+                }
+                (Ok(actual), Some(expected)) => {
+                    failed += 1;
+                    format!("FAIL (expected {expected}, got {actual})")
+                }
+            };
+            println!("{} part {}: {status}", entry.id, part.part);
+        }
+    }
+
+    println!();
+    println!(
+        "{passed} passed, {failed} failed, {missing} missing, {unimplemented} not implemented"
+    );
+    Ok(failed == 0)
+}
@@ -0,0 +1,218 @@
+//! Compares solutions against their reference solutions.
+
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::PathBuf,
+};
+
+use advent_of_code::{
+    Answer, Rng, Selection,
+    days::{self, Entry},
+};
+
+use crate::{
+    default_input_path,
+    parallel::{self, Outcome},
+    read_input, select_all,
+};
+
+#[derive(Debug, clap::Args)]
+pub struct CrosscheckArgs {
+    /// Puzzles to check: years (`2024`), dates (`2025-12-01`) or ranges of days
+    /// (`2025-12-01..05`). Checks every day with a reference solution if none are given.
+    puzzles: Vec<Selection>,
+
+    /// Seed of the first random input. Each following input uses the next seed.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Number of random inputs to check each day on.
+    #[arg(short = 'n', long, default_value_t = 100)]
+    cases: u64,
+
+    /// Size of the random inputs, kept small by default so that reference solutions stay fast.
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+/// What a solution produced for one part.
+#[derive(Debug, Clone, PartialEq)]
+enum PartResult {
+    Answer(Answer),
+    Error(String),
+    NotImplemented,
+}
+
+impl PartResult {
+    /// Returns whether two solutions agree. Errors agree with each other, since both solutions
+    /// rejected the input, and parts missing from either solution aren't compared.
+    fn agrees_with(&self, other: &PartResult) -> bool {
+        match (self, other) {
+            (PartResult::NotImplemented, _) | (_, PartResult::NotImplemented) => true,
+            (PartResult::Error(_), PartResult::Error(_)) => true,
+            (a, b) => a == b,
+        }
+    }
+}
+
+impl Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartResult::Answer(answer) => write!(f, "{answer}"),
+            PartResult::Error(err) => write!(f, "error: {err}"),
+            PartResult::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+/// Returns the result of each part, with failures of the whole run applying to every part.
+fn part_results(outcome: Outcome, parts: u8) -> Vec<PartResult> {
+    let failed = |message: String| vec![PartResult::Error(message); parts as usize];
+    match outcome {
+        Outcome::Panicked(message) => failed(format!("panicked: {message}")),
+        Outcome::Ran(Err(err)) => failed(err.to_string()),
+        Outcome::Ran(Ok(run)) => run
+            .parts
+            .into_iter()
+            .map(|part| match part.answer {
+                Ok(answer) => PartResult::Answer(answer),
+                Err(err) if err.is_not_implemented() => PartResult::NotImplemented,
+                Err(err) => PartResult::Error(err.to_string()),
+            })
+            .collect(),
+    }
+}
+
+/// An input to compare the solutions on.
+struct Case {
+    /// Where the input came from, for reporting divergences.
+    source: String,
+    input: String,
+    /// Whether the input only exists in memory and should be saved if the solutions diverge.
+    generated: bool,
+}
+
+/// Returns the day's sample inputs and real input, in that order.
+fn stored_cases(entry: &Entry) -> Result<Vec<Case>, String> {
+    let prefix = format!("{}-sample", entry.id);
+    let mut paths: Vec<PathBuf> = match fs::read_dir("inputs") {
+        Ok(dir) => dir
+            .map(|file| file.map(|file| file.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("inputs: {err}"))?
+            .into_iter()
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "txt")
+                    && path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix))
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    paths.sort();
+    let real = default_input_path(entry);
+    if real.exists() {
+        paths.push(real);
+    }
+    paths
+        .into_iter()
+        .map(|path| {
+            let input = read_input(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+            let source = path.display().to_string();
+            Ok(Case {
+                source,
+                input,
+                generated: false,
+            })
+        })
+        .collect()
+}
+
+/// Runs both solutions on `case`, and returns a description of how they diverged, if they did.
+fn compare(entry: &Entry, case: &Case) -> Option<String> {
+    let solution = parallel::isolated(|| entry.run(&case.input, None));
+    let reference = parallel::isolated(|| entry.run_reference(&case.input, None).unwrap());
+    let solution = part_results(solution, entry.parts);
+    let reference = part_results(reference, entry.parts);
+    let differences: Vec<String> = solution
+        .iter()
+        .zip(&reference)
+        .enumerate()
+        .filter(|(_, (solution, reference))| !solution.agrees_with(reference))
+        .map(|(i, (solution, reference))| {
+            format!(
+                "  part {}: solution gave {solution}, reference gave {reference}",
+                i + 1
+            )
+        })
+        .collect();
+    (!differences.is_empty()).then(|| differences.join("\n"))
+}
+
+/// Checks a day on each of its cases, stopping at the first divergence. Returns `false` if
+/// the solutions diverged.
+fn crosscheck_day(entry: &Entry, args: &CrosscheckArgs) -> Result<bool, String> {
+    let mut cases = stored_cases(entry)?;
+    let stored = cases.len();
+    if entry.has_generator() {
+        for seed in args.seed..args.seed.saturating_add(args.cases) {
+            let input = entry.generate(&mut Rng::new(seed), args.size).unwrap();
+            cases.push(Case {
+                source: format!("random input (seed {seed}, size {})", args.size),
+                input,
+                generated: true,
+            });
+        }
+    }
+    if cases.is_empty() {
+        println!("{}: no inputs to check", entry.id);
+        return Ok(true);
+    }
+
+    let total = cases.len();
+    for case in cases {
+        let Some(differences) = compare(entry, &case) else {
+            continue;
+        };
+        println!("{}: DIVERGED on {}", entry.id, case.source);
+        println!("{differences}");
+        if case.generated {
+            let path = PathBuf::from(format!("inputs/{}-crosscheck.txt", entry.id));
+            fs::write(&path, &case.input).map_err(|err| format!("{}: {err}", path.display()))?;
+            println!("  input saved to {}", path.display());
+        }
+        return Ok(false);
+    }
+    println!(
+        "{}: {total} inputs agree ({stored} stored, {} random)",
+        entry.id,
+        total - stored
+    );
+    Ok(true)
+}
+
+/// Runs the selected days with reference solutions, or every such day, and their reference
+/// solutions on the same inputs. Returns `Ok(false)` if any day's solutions diverged.
+pub fn crosscheck(args: &CrosscheckArgs) -> Result<bool, String> {
+    let entries: Vec<&Entry> = if args.puzzles.is_empty() {
+        days::ENTRIES.iter().collect()
+    } else {
+        select_all(&args.puzzles)?
+    };
+    let entries: Vec<&Entry> = entries
+        .into_iter()
+        .filter(|entry| entry.has_reference())
+        .collect();
+    if entries.is_empty() {
+        return Err("no reference solutions registered for the selected puzzles".to_string());
+    }
+
+    let mut ok = true;
+    for entry in entries {
+        ok &= crosscheck_day(entry, args)?;
+    }
+    Ok(ok)
+}
@@ -0,0 +1,429 @@
+use std::{
+    io::{IsTerminal, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+};
+
+use advent_of_code::{
+    Answer, PuzzleId, Rng, Selection, Year,
+    answers::{self, Answers},
+    days::{self, Entry},
+    site::{self, Site},
+    trace, trailing_blank_lines, warn,
+};
+use clap::{Parser, Subcommand};
+use output::Format;
+
+mod bench;
+mod check;
+mod crosscheck;
+mod new;
+mod output;
+mod parallel;
+mod status;
+mod submit;
+mod watch;
+
+/// Runs Advent of Code solutions.
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Show diagnostics from solutions on standard error: `-v` for info, `-vv` for debug and
+    /// `-vvv` for trace messages. Only warnings are shown by default.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Append diagnostics to this file instead of standard error.
+    #[arg(long, global = true)]
+    log_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Record an accepted answer in the answers file.
+    Record {
+        /// Date of the puzzle, as `YYYY-12-DD`.
+        date: PuzzleId,
+
+        /// Part the answer is for.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// The accepted answer. If omitted, the solution is run on its input to compute it.
+        answer: Option<String>,
+    },
+
+    /// Create the solution, input and sample files for a new day.
+    New {
+        /// Date of the puzzle, as `YYYY-12-DD`.
+        date: PuzzleId,
+    },
+
+    /// Download a puzzle's input into `inputs/`, unless it was already downloaded.
+    Fetch {
+        /// Date of the puzzle, as `YYYY-12-DD`.
+        date: PuzzleId,
+    },
+
+    /// Submit an answer to the website, unless it is known to be wrong.
+    Submit {
+        /// Date of the puzzle, as `YYYY-12-DD`.
+        date: PuzzleId,
+
+        /// Part the answer is for.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// The answer to submit. If omitted, the solution is run on its input to compute it.
+        answer: Option<String>,
+    },
+
+    /// Show a grid of the stars earned in each year.
+    Status {
+        /// Years to show. Shows every year with a solution or an answer if none are given.
+        years: Vec<Year>,
+    },
+
+    /// Benchmark solutions and compare against the previous benchmark run.
+    Bench(bench::BenchArgs),
+
+    /// Compare solutions against their reference solutions on sample, real and random inputs.
+    Crosscheck(crosscheck::CrosscheckArgs),
+
+    /// Rebuild and rerun a puzzle whenever its solution, inputs or accepted answers change.
+    Watch {
+        /// Date of the puzzle, as `YYYY-12-DD`.
+        date: PuzzleId,
+    },
+
+    /// Print a random input for a puzzle.
+    Gen {
+        /// Date of the puzzle, as `YYYY-12-DD`.
+        date: PuzzleId,
+
+        /// Seed of the random number generator. The same seed and size give the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How large the input is, for example its number of lines. What it counts depends on
+        /// the puzzle.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct RunArgs {
+    /// Puzzles to run: a year (`2024`), a date (`2025-12-01`) or a range of days in one year
+    /// (`2025-12-01..05`).
+    #[arg(required_unless_present_any = ["all", "check"], conflicts_with = "all")]
+    puzzles: Option<Selection>,
+
+    /// Run all registered solutions.
+    #[arg(long)]
+    all: bool,
+
+    /// Only run the given part.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read input from this file instead of `inputs/<date>.txt`. Use `-` for standard input.
+    /// Only allowed when running a single puzzle.
+    #[arg(short, long, conflicts_with_all = ["all", "check"])]
+    input: Option<PathBuf>,
+
+    /// Show how long parsing and each part took.
+    #[arg(short, long)]
+    time: bool,
+
+    /// Compare answers against the answers file instead of printing them. Checks every day
+    /// unless puzzles are given.
+    #[arg(long)]
+    check: bool,
+
+    /// When running several puzzles, run them on this many threads and print the results as a
+    /// table. 0 uses one thread per CPU.
+    #[arg(short, long, conflicts_with = "check")]
+    jobs: Option<usize>,
+
+    /// How to print the results.
+    #[arg(short, long, value_enum, default_value_t, conflicts_with = "check")]
+    format: Format,
+}
+
+/// Reads an input from a file, or from standard input if `path` is `-`, warning about
+/// trailing blank lines since they usually come from pasting it by hand.
+fn read_input(path: &Path) -> std::io::Result<String> {
+    let input = if path.as_os_str() == "-" {
+        if std::io::stdin().is_terminal() {
+            eprintln!("Waiting for input...");
+        }
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        std::fs::read_to_string(path)?
+    };
+    let blank = trailing_blank_lines(&input);
+    if blank > 0 {
+        warn!(
+            "{}: ignoring {blank} trailing blank line(s)",
+            path.display()
+        );
+    }
+    Ok(input)
+}
+
+fn default_input_path(entry: &Entry) -> PathBuf {
+    site::input_path("inputs", entry.id)
+}
+
+fn find_entry(date: PuzzleId) -> Result<&'static Entry, String> {
+    days::find(date).ok_or_else(|| format!("no solution registered for {date}"))
+}
+
+/// Returns the solutions for the selected puzzles, or for every puzzle if `selection` is
+/// `None`. Selections matching no solution are an error.
+fn select_entries(selection: Option<&Selection>) -> Result<Vec<&'static Entry>, String> {
+    let Some(selection) = selection else {
+        return Ok(days::ENTRIES.iter().collect());
+    };
+    let entries: Vec<&Entry> = days::select(selection).collect();
+    if entries.is_empty() {
+        return Err(format!("no solutions registered for {selection}"));
+    }
+    Ok(entries)
+}
+
+/// Returns the solutions for every puzzle in any of the selections, sorted by date.
+fn select_all(selections: &[Selection]) -> Result<Vec<&'static Entry>, String> {
+    let mut entries: Vec<&Entry> = Vec::new();
+    for selection in selections {
+        entries.extend(select_entries(Some(selection))?);
+    }
+    entries.sort_by_key(|entry| entry.id);
+    entries.dedup_by_key(|entry| entry.id);
+    Ok(entries)
+}
+
+/// Runs the selected parts and prints their answers. Returns `false` if any part failed.
+fn run(entry: &Entry, input: &str, args: &RunArgs) -> bool {
+    let run = match entry.run(input, args.part) {
+        Ok(run) => run,
+        Err(err) => {
+            eprintln!("error: {}: {err}", entry.id);
+            return false;
+        }
+    };
+    if args.time {
+        println!("Parse: ({:.2?})", run.parse_time);
+    }
+    let mut ok = true;
+    for part in run.parts {
+        match part.answer {
+            Ok(answer) => {
+                let time = if args.time {
+                    format!(" ({:.2?})", part.time)
+                } else {
+                    String::new()
+                };
+                if answer.is_multiline() {
+                    println!("Part {}:{time}\n{answer}", part.part);
+                } else {
+                    println!("Part {}: {answer}{time}", part.part);
+                }
+            }
+            Err(err) if err.is_not_implemented() => {
+                println!("Part {}: not implemented", part.part);
+            }
+            Err(err) => {
+                eprintln!("error: {} part {}: {err}", entry.id, part.part);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+/// Prints the results of a structured [`Format`]. Returns `false` if any part failed.
+fn print_rows(outcomes: Vec<(&Entry, parallel::Outcome)>, args: &RunArgs) -> bool {
+    let rows: Vec<output::Row> = outcomes
+        .into_iter()
+        .flat_map(|(entry, outcome)| output::rows(entry, outcome, args.part))
+        .collect();
+    output::print(args.format, &rows);
+    rows.iter().all(|row| {
+        matches!(
+            row.status,
+            output::Status::Ok | output::Status::NotImplemented
+        )
+    })
+}
+
+fn run_many(entries: &[&Entry], args: &RunArgs) -> bool {
+    let jobs = match args.jobs {
+        Some(0) => thread::available_parallelism().map_or(1, usize::from),
+        Some(jobs) => jobs,
+        None => 1,
+    };
+    if args.format != Format::Text {
+        return print_rows(parallel::run_all(entries, args.part, jobs), args);
+    }
+    if args.jobs.is_some() {
+        return parallel::run_parallel(entries, args, jobs);
+    }
+    let mut ok = true;
+    let mut first = true;
+    for entry in entries {
+        let path = default_input_path(entry);
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Skipping {}: {}: {err}", entry.id, path.display());
+                continue;
+            }
+        };
+        if !first {
+            println!();
+        }
+        first = false;
+        println!("{}", entry.id);
+        ok &= run(entry, &input, args);
+    }
+    ok
+}
+
+fn run_one(date: PuzzleId, args: &RunArgs) -> Result<bool, String> {
+    let entry = find_entry(date)?;
+    if let Some(part) = args.part.filter(|&part| part > entry.parts) {
+        return Err(format!("{date} has no part {part}"));
+    }
+    let path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| default_input_path(entry));
+    let input = read_input(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    if args.format != Format::Text {
+        let outcome = parallel::run_isolated(entry, &input, args.part);
+        return Ok(print_rows(vec![(entry, outcome)], args));
+    }
+    Ok(run(entry, &input, args))
+}
+
+fn run_selection(selection: &Selection, args: &RunArgs) -> Result<bool, String> {
+    if let Some(date) = selection.single() {
+        return run_one(date, args);
+    }
+    if args.input.is_some() {
+        return Err("--input can only be used when running a single puzzle".to_string());
+    }
+    Ok(run_many(&select_entries(Some(selection))?, args))
+}
+
+/// Parses the answer given on the command line, or else computes it from the day's input.
+fn given_or_computed_answer(
+    entry: &Entry,
+    part: u8,
+    answer: Option<&str>,
+) -> Result<Answer, String> {
+    let date = entry.id;
+    if let Some(answer) = answer {
+        return Ok(answer.parse().unwrap());
+    }
+    let path = default_input_path(entry);
+    let input = read_input(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let mut run = entry
+        .run(&input, Some(part))
+        .map_err(|err| format!("{date}: {err}"))?;
+    run.parts
+        .pop()
+        .unwrap()
+        .answer
+        .map_err(|err| format!("{date} part {part}: {err}"))
+}
+
+/// Records an answer, computing it from the day's input if it isn't given.
+fn record(date: PuzzleId, part: u8, answer: Option<&str>) -> Result<bool, String> {
+    let entry = find_entry(date)?;
+    if part > entry.parts {
+        return Err(format!("{date} has no part {part}"));
+    }
+    let answer = given_or_computed_answer(entry, part, answer)?;
+    let mut answers = Answers::load(answers::DEFAULT_PATH).map_err(|err| err.to_string())?;
+    answers.set(date, part, &answer);
+    answers
+        .save(answers::DEFAULT_PATH)
+        .map_err(|err| format!("{}: {err}", answers::DEFAULT_PATH))?;
+    println!("Recorded {date} part {part}: {answer}");
+    Ok(true)
+}
+
+/// Downloads the input for `date` if it isn't cached yet.
+fn fetch(date: PuzzleId) -> Result<bool, String> {
+    let (path, downloaded) = Site::from_env()
+        .fetch("inputs", date)
+        .map_err(|err| format!("{date}: {err}"))?;
+    if downloaded {
+        println!("Downloaded {}", path.display());
+    } else {
+        println!("Already downloaded {}", path.display());
+    }
+    Ok(true)
+}
+
+/// Prints a random input for `date`.
+fn generate(date: PuzzleId, seed: u64, size: usize) -> Result<bool, String> {
+    let input = find_entry(date)?
+        .generate(&mut Rng::new(seed), size)
+        .ok_or_else(|| format!("no input generator registered for {date}"))?;
+    print!("{input}");
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let args = &cli.run;
+    trace::set_level(trace::Level::from_verbosity(cli.verbose));
+    if let Some(path) = &cli.log_file
+        && let Err(err) = trace::set_log_file(path)
+    {
+        eprintln!("error: {}: {err}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    let result = match &cli.command {
+        Some(Command::Record { date, part, answer }) => record(*date, *part, answer.as_deref()),
+        Some(Command::New { date }) => new::new(*date),
+        Some(Command::Fetch { date }) => fetch(*date),
+        Some(Command::Submit { date, part, answer }) => {
+            submit::submit(*date, *part, answer.as_deref())
+        }
+        Some(Command::Status { years }) => status::status(years),
+        Some(Command::Bench(bench_args)) => bench::bench(bench_args),
+        Some(Command::Crosscheck(crosscheck_args)) => crosscheck::crosscheck(crosscheck_args),
+        Some(Command::Gen { date, seed, size }) => generate(*date, *seed, *size),
+        Some(Command::Watch { date }) => watch::watch(*date),
+        None if args.check => check::check(args.puzzles.as_ref()),
+        None if args.all => select_entries(None).map(|entries| run_many(&entries, args)),
+        None => run_selection(args.puzzles.as_ref().unwrap(), args),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Scaffolds the files for a new day.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+
+use advent_of_code::PuzzleId;
+
+const TEMPLATE: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/days/template.rs"));
+
+/// Creates the solution, input and sample files for `date`. Nothing is created if any of them
+/// already exists.
+pub fn new(date: PuzzleId) -> Result<bool, String> {
+    let sidecar = format!(
+        "# Expected answers for {date}-sample.txt. Uncomment them to test the sample.\n\
+         # part1 = 0\n\
+         # part2 = 0\n"
+    );
+    let files = [
+        (
+            PathBuf::from(format!("src/days/{}/{}.rs", date.year, date.day)),
+            TEMPLATE,
+        ),
+        (PathBuf::from(format!("inputs/{date}.txt")), ""),
+        (PathBuf::from(format!("inputs/{date}-sample.txt")), ""),
+        (
+            PathBuf::from(format!("inputs/{date}-sample.toml")),
+            &sidecar,
+        ),
+    ];
+    let existing: Vec<String> = files
+        .iter()
+        .filter(|(path, _)| path.exists())
+        .map(|(path, _)| path.display().to_string())
+        .collect();
+    if !existing.is_empty() {
+        return Err(format!("refusing to overwrite {}", existing.join(", ")));
+    }
+
+    for (path, contents) in files {
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        println!("Created {}", path.display());
+    }
+    Ok(true)
+}
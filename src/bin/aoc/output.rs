@@ -0,0 +1,150 @@
+//! Renders run results as JSON, CSV or a Markdown table.
+
+use std::time::Duration;
+
+use advent_of_code::days::Entry;
+use serde::{Deserialize, Serialize};
+
+use crate::parallel::Outcome;
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// `Part 1: ...` lines, or a table when running with `--jobs`.
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Error,
+    NotImplemented,
+    Panicked,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::NotImplemented => "not_implemented",
+            Status::Panicked => "panicked",
+        }
+    }
+}
+
+/// The result of running one part.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Row {
+    pub date: String,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Time taken by the part, in seconds.
+    pub duration: Option<f64>,
+    pub status: Status,
+}
+
+/// Returns a row for each part that was run. Error messages are printed to standard error,
+/// since rows only record that a part failed.
+pub fn rows(entry: &Entry, outcome: Outcome, part: Option<u8>) -> Vec<Row> {
+    let date = entry.id.to_string();
+    let failed = |status| {
+        (1..=entry.parts)
+            .filter(|&n| part.is_none_or(|part| part == n))
+            .map(|n| Row {
+                date: date.clone(),
+                part: n,
+                answer: None,
+                duration: None,
+                status,
+            })
+            .collect()
+    };
+    let run = match outcome {
+        Outcome::Ran(Ok(run)) => run,
+        Outcome::Ran(Err(err)) => {
+            eprintln!("error: {date}: {err}");
+            return failed(Status::Error);
+        }
+        Outcome::Panicked(message) => {
+            eprintln!("error: {date}: panicked: {message}");
+            return failed(Status::Panicked);
+        }
+    };
+    run.parts
+        .into_iter()
+        .map(|part| {
+            let (answer, status) = match part.answer {
+                Ok(answer) => (Some(answer.to_string()), Status::Ok),
+                Err(err) if err.is_not_implemented() => (None, Status::NotImplemented),
+                Err(err) => {
+                    eprintln!("error: {date} part {}: {err}", part.part);
+                    (None, Status::Error)
+                }
+            };
+            Row {
+                date: date.clone(),
+                part: part.part,
+                duration: (status == Status::Ok).then_some(part.time.as_secs_f64()),
+                answer,
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Escapes a Markdown table cell.
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Prints the rows in the given format, which must not be [`Format::Text`].
+pub fn print(format: Format, rows: &[Row]) {
+    match format {
+        Format::Text => unreachable!("text output is printed while running"),
+        Format::Json => println!("{}", serde_json::to_string_pretty(rows).unwrap()),
+        Format::Csv => {
+            println!("date,part,answer,duration,status");
+            for row in rows {
+                println!(
+                    "{},{},{},{},{}",
+                    row.date,
+                    row.part,
+                    csv_field(row.answer.as_deref().unwrap_or_default()),
+                    row.duration.map_or(String::new(), |secs| secs.to_string()),
+                    row.status.as_str(),
+                );
+            }
+        }
+        Format::Markdown => {
+            println!("| Date | Part | Answer | Duration | Status |");
+            println!("| --- | ---: | --- | ---: | --- |");
+            for row in rows {
+                let duration = row.duration.map_or(String::new(), |secs| {
+                    format!("{:.2?}", Duration::from_secs_f64(secs))
+                });
+                println!(
+                    "| {} | {} | {} | {duration} | {} |",
+                    row.date,
+                    row.part,
+                    markdown_cell(row.answer.as_deref().unwrap_or_default()),
+                    row.status.as_str().replace('_', " "),
+                );
+            }
+        }
+    }
+}
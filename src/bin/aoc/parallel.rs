@@ -0,0 +1,178 @@
+//! Runs several days concurrently and summarises the results in a table.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use advent_of_code::{
+    Result,
+    days::{Entry, Run},
+};
+
+use crate::{RunArgs, default_input_path, read_input};
+
+/// What happened when running one day.
+pub enum Outcome {
+    Ran(Result<Run>),
+    /// The solution panicked, with the given message.
+    Panicked(String),
+}
+
+thread_local! {
+    /// Where the last panic on this thread happened, recorded by the hook set in
+    /// [`with_quiet_panics`] in place of the default one, which would print it.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    };
+    match PANIC_LOCATION.take() {
+        Some(location) => format!("{message} at {location}"),
+        None => message,
+    }
+}
+
+/// Runs `f` with the default panic hook, which prints panics, replaced by one which records
+/// their location for [`panic_message`].
+fn with_quiet_panics<T>(f: impl FnOnce() -> T) -> T {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(|location| location.to_string());
+        PANIC_LOCATION.set(location);
+    }));
+    let result = f();
+    panic::set_hook(default_hook);
+    result
+}
+
+/// Runs a solution, catching any panic. Must be called inside [`with_quiet_panics`].
+fn catch_panics(run: impl FnOnce() -> Result<Run>) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(run)) {
+        Ok(run) => Outcome::Ran(run),
+        Err(payload) => Outcome::Panicked(panic_message(&*payload)),
+    }
+}
+
+/// Runs a solution, catching any panic.
+pub fn isolated(run: impl FnOnce() -> Result<Run>) -> Outcome {
+    with_quiet_panics(|| catch_panics(run))
+}
+
+/// Runs a day on the given input, catching any panic.
+pub fn run_isolated(entry: &Entry, input: &str, part: Option<u8>) -> Outcome {
+    isolated(|| entry.run(input, part))
+}
+
+/// Runs the given days on their inputs on `jobs` threads, isolating panics, and returns their
+/// outcomes sorted by date. Days whose input can't be read are skipped with a message.
+pub fn run_all<'e>(
+    entries: &[&'e Entry],
+    part: Option<u8>,
+    jobs: usize,
+) -> Vec<(&'e Entry, Outcome)> {
+    let next = AtomicUsize::new(0);
+    let run = || {
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..jobs.clamp(1, entries.len().max(1)))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut outcomes = Vec::new();
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(entry) = entries.get(i) else {
+                                break outcomes;
+                            };
+                            let path = default_input_path(entry);
+                            let outcome = read_input(&path)
+                                .map(|input| catch_panics(|| entry.run(&input, part)))
+                                .map_err(|err| format!("{}: {err}", path.display()));
+                            outcomes.push((i, outcome));
+                        }
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect()
+        })
+    };
+    let mut outcomes: Vec<(usize, Result<Outcome, String>)> = with_quiet_panics(run);
+    outcomes.sort_by_key(|&(i, _)| i);
+
+    let mut ran = Vec::new();
+    for (i, outcome) in outcomes {
+        match outcome {
+            Ok(outcome) => ran.push((entries[i], outcome)),
+            Err(err) => eprintln!("Skipping {}: {err}", entries[i].id),
+        }
+    }
+    ran
+}
+
+/// Returns the first line of a multi-line answer, marking that it was cut short.
+fn first_line(answer: &str) -> String {
+    match answer.split_once('\n') {
+        Some((first, _)) => format!("{first}…"),
+        None => answer.to_string(),
+    }
+}
+
+fn print_row(date: &str, phase: &str, result: &str, time: Option<Duration>) {
+    let time = time.map_or(String::new(), |time| format!("{time:.2?}"));
+    let row = format!("{date:<10}  {phase:<5}  {result:<20}  {time:>10}");
+    println!("{}", row.trim_end());
+}
+
+/// Runs the given days on `jobs` threads, then prints their answers and timings sorted by
+/// date. A panic in one day is reported in its row without stopping the others. Returns
+/// `false` if any day failed or panicked.
+pub fn run_parallel(entries: &[&Entry], args: &RunArgs, jobs: usize) -> bool {
+    let outcomes = run_all(entries, args.part, jobs);
+    let mut ok = true;
+    print_row("date", "phase", "answer", None);
+    for (entry, outcome) in outcomes {
+        let date = entry.id.to_string();
+        match outcome {
+            Outcome::Panicked(message) => {
+                print_row(&date, "", &format!("panicked: {message}"), None);
+                ok = false;
+            }
+            Outcome::Ran(Err(err)) => {
+                print_row(&date, "parse", &format!("error: {err}"), None);
+                ok = false;
+            }
+            Outcome::Ran(Ok(run)) => {
+                print_row(&date, "parse", "", Some(run.parse_time));
+                for part in run.parts {
+                    let phase = format!("part{}", part.part);
+                    match part.answer {
+                        Ok(answer) => {
+                            let answer = first_line(&answer.to_string());
+                            print_row(&date, &phase, &answer, Some(part.time));
+                        }
+                        Err(err) if err.is_not_implemented() => {
+                            print_row(&date, &phase, "not implemented", None);
+                        }
+                        Err(err) => {
+                            print_row(&date, &phase, &format!("error: {err}"), None);
+                            ok = false;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    ok
+}
@@ -0,0 +1,75 @@
+//! Shows which puzzles have been solved.
+
+use std::collections::BTreeSet;
+
+use advent_of_code::{
+    PuzzleId, Year,
+    answers::{self, Answers},
+    days,
+    submissions::{self, Submissions},
+};
+
+/// Prints a grid of stars for each of the given years, or for every year with a solution, an
+/// accepted answer or a submission if none are given.
+///
+/// A part earns a star if its answer is in the answers file or was accepted by the website.
+/// As on the website, the last puzzle of a year earns its second star once every other star
+/// of the year has been earned.
+pub fn status(years: &[Year]) -> Result<bool, String> {
+    let answers = Answers::load(answers::DEFAULT_PATH).map_err(|err| err.to_string())?;
+    let history = Submissions::load(submissions::DEFAULT_PATH)
+        .map_err(|err| format!("{}: {err}", submissions::DEFAULT_PATH))?;
+
+    let years: BTreeSet<Year> = if years.is_empty() {
+        days::ENTRIES
+            .iter()
+            .map(|entry| entry.id)
+            .chain(answers.puzzles())
+            .chain(history.puzzles())
+            .map(|id| id.year)
+            .collect()
+    } else {
+        years.iter().copied().collect()
+    };
+
+    for (i, &year) in years.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let ids: Vec<PuzzleId> = (1..=year.days())
+            .map(|day| PuzzleId::new(year.get(), day))
+            .collect();
+        let solved = |id, part| answers.get(id, part).is_some() || history.solved(id, part);
+        let mut stars: Vec<u8> = ids
+            .iter()
+            .map(|&id| (1..=2).filter(|&part| solved(id, part)).count() as u8)
+            .collect();
+        let (last, others) = stars.split_last_mut().unwrap();
+        if *last == 1 && others.iter().all(|&stars| stars == 2) {
+            *last = 2;
+        }
+
+        let total: u32 = stars.iter().map(|&stars| u32::from(stars)).sum();
+        println!("{year}: {total}/{} stars", 2 * u32::from(year.days()));
+        let header: String = ids
+            .iter()
+            .map(|id| format!("{:>3}", id.day.get()))
+            .collect();
+        let row: String = ids
+            .iter()
+            .zip(&stars)
+            .map(|(&id, &stars)| {
+                let cell = match stars {
+                    2 => "**",
+                    1 => "* ",
+                    _ if days::find(id).is_some() => ". ",
+                    _ => "  ",
+                };
+                format!(" {cell}")
+            })
+            .collect();
+        println!("{header}");
+        println!("{}", row.trim_end());
+    }
+    Ok(true)
+}
@@ -0,0 +1,55 @@
+//! Submits answers to the website, keeping a local history of the verdicts.
+
+use advent_of_code::{
+    PuzzleId,
+    answers::{self, Answers},
+    site::{Site, Verdict},
+    submissions::{self, Submissions},
+};
+
+use crate::{find_entry, given_or_computed_answer};
+
+/// Submits an answer, computing it from the day's input if it isn't given. Returns `Ok(true)`
+/// if the answer was accepted.
+pub fn submit(date: PuzzleId, part: u8, answer: Option<&str>) -> Result<bool, String> {
+    let entry = find_entry(date)?;
+    if part > entry.parts {
+        return Err(format!("{date} has no part {part}"));
+    }
+    let answer = given_or_computed_answer(entry, part, answer)?;
+    let mut history = Submissions::load(submissions::DEFAULT_PATH)
+        .map_err(|err| format!("{}: {err}", submissions::DEFAULT_PATH))?;
+    let now = submissions::now();
+    if let Err(refusal) = history.check(date, part, &answer, now) {
+        println!("Not submitting {answer}: {refusal}");
+        return Ok(false);
+    }
+
+    println!("Submitting {date} part {part}: {answer}");
+    let response = Site::from_env()
+        .submit(date, part, &answer)
+        .map_err(|err| format!("{date} part {part}: {err}"))?;
+    // Answers that weren't judged don't tell us anything, but the cooldown still applies.
+    if matches!(response.verdict, Verdict::TooSoon | Verdict::WrongLevel) {
+        if let Some(wait) = response.wait {
+            history.cooldown_until = now + wait.as_secs();
+        }
+    } else {
+        history.record(date, part, &answer, now, &response);
+    }
+    history
+        .save(submissions::DEFAULT_PATH)
+        .map_err(|err| format!("{}: {err}", submissions::DEFAULT_PATH))?;
+    println!("{}", response.message);
+
+    if response.verdict != Verdict::Correct {
+        return Ok(false);
+    }
+    let mut answers = Answers::load(answers::DEFAULT_PATH).map_err(|err| err.to_string())?;
+    answers.set(date, part, &answer);
+    answers
+        .save(answers::DEFAULT_PATH)
+        .map_err(|err| format!("{}: {err}", answers::DEFAULT_PATH))?;
+    println!("Recorded {date} part {part}: {answer}");
+    Ok(true)
+}
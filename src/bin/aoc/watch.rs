@@ -0,0 +1,158 @@
+//! Reruns a day whenever its solution or inputs change.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use advent_of_code::{
+    Answer, PuzzleId,
+    answers::{self, Answers},
+};
+
+use crate::output::{Row, Status};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Returns the files whose changes trigger a rerun: the day's solution, its inputs and samples,
+/// and the answers file. Files which don't exist yet are included, so that creating them
+/// counts as a change.
+fn watched_files(date: PuzzleId) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/days/{}/{}.rs", date.year, date.day)),
+        PathBuf::from(format!("inputs/{date}.txt")),
+        PathBuf::from(answers::DEFAULT_PATH),
+    ];
+    let prefix = format!("{date}-sample");
+    if let Ok(dir) = fs::read_dir("inputs") {
+        let mut samples: Vec<PathBuf> = dir
+            .filter_map(|file| Some(file.ok()?.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+            })
+            .collect();
+        samples.sort();
+        files.extend(samples);
+    }
+    files
+}
+
+fn modification_times(files: &[PathBuf]) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Rebuilds the runner and runs the day with it, returning its results. Build errors and
+/// diagnostics are shown as they happen. Returns `None` if it couldn't be built or run.
+fn rebuild_and_run(date: PuzzleId) -> Option<Vec<Row>> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet", "--bin", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+        .args(["--", &date.to_string(), "--format", "json"])
+        .stderr(Stdio::inherit());
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("error: running cargo: {err}");
+            return None;
+        }
+    };
+    // The runner exits with an error if a part failed, but still prints its results.
+    serde_json::from_slice(&output.stdout).ok()
+}
+
+/// Prints each part's answer, how it changed since the previous run and whether it matches the
+/// accepted answer.
+fn print_rows(date: PuzzleId, rows: &[Row], previous: Option<&[Row]>) {
+    let answers = Answers::load(answers::DEFAULT_PATH)
+        .inspect_err(|err| eprintln!("warning: {err}"))
+        .unwrap_or_default();
+    for row in rows {
+        let Some(answer) = &row.answer else {
+            let status = match row.status {
+                Status::NotImplemented => "not implemented",
+                Status::Panicked => "panicked",
+                Status::Ok | Status::Error => "failed",
+            };
+            println!("Part {}: {status}", row.part);
+            continue;
+        };
+        let mut notes = Vec::new();
+        let before = previous
+            .and_then(|rows| rows.iter().find(|before| before.part == row.part))
+            .and_then(|before| before.answer.as_ref());
+        match before {
+            Some(before) if before != answer => notes.push(format!("was {before}")),
+            Some(_) => {}
+            None if previous.is_some() => notes.push("new".to_string()),
+            None => {}
+        }
+        let actual: Answer = answer.parse().unwrap();
+        match answers.get(date, row.part) {
+            Some(expected) if expected == actual => notes.push("correct".to_string()),
+            Some(expected) => notes.push(format!("expected {expected}")),
+            None => {}
+        }
+        let time = row.duration.map_or(String::new(), |secs| {
+            format!(" ({:.2?})", Duration::from_secs_f64(secs))
+        });
+        if notes.is_empty() {
+            println!("Part {}: {answer}{time}", row.part);
+        } else {
+            println!("Part {}: {answer}{time} [{}]", row.part, notes.join(", "));
+        }
+    }
+}
+
+/// Reruns `date` whenever its files change, until interrupted.
+pub fn watch(date: PuzzleId) -> Result<bool, String> {
+    crate::find_entry(date)?;
+    let mut times = BTreeMap::new();
+    let mut previous: Option<Vec<Row>> = None;
+    loop {
+        let files = watched_files(date);
+        let current = modification_times(&files);
+        let changed: Vec<String> = current
+            .iter()
+            .filter(|&(path, time)| times.get(path) != Some(time))
+            .map(|(path, _)| path.display().to_string())
+            .collect();
+        if !times.is_empty() && changed.is_empty() {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        if times.is_empty() {
+            println!(
+                "Watching {} and the inputs for {date}. Press Ctrl-C to stop.",
+                files[0].display()
+            );
+        } else {
+            println!("\nChanged: {}", changed.join(", "));
+        }
+        times = current;
+
+        match rebuild_and_run(date) {
+            Some(rows) => {
+                print_rows(date, &rows, previous.as_deref());
+                previous = Some(rows);
+            }
+            None => println!("Build or run failed; waiting for changes"),
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Registry of every day's solution.
+//!
+//! The modules and the [`ENTRIES`] table are generated by `build.rs` from the files in
+//! `src/days/`, so adding a day only requires creating `src/days/YYYY/DD.rs` containing a
+//! `Problem` type which implements [`Solution`]. Each year's days are grouped in a module
+//! `yYYYY`, with the day's module named `dayDD`. A day with a reference solution or an input
+//! generator declares them with [`register!`].
+
+use std::{
+    borrow::Cow,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, InputGenerator, Normalize, PuzzleId, Result, Rng, Selection, Solution};
+
+/// Declares a day's reference solution and input generator, which `build.rs` adds to its
+/// [`Entry`] through the `register` function this defines:
+///
+/// ```ignore
+/// register!(reference = Reference, generator = Problem);
+/// ```
+///
+/// `build.rs` looks for lines starting with `register!(`. Should it miss one, `register` is
+/// left unused, which is a warning, so the day can't silently lose its extras.
+macro_rules! register {
+    ($($kind:ident = $ty:ty),+ $(,)?) => {
+        /// Adds the reference solution and input generator declared with `register!`.
+        pub(crate) const fn register(entry: crate::days::Entry) -> crate::days::Entry {
+            $(let entry = register!(@$kind entry, $ty);)+
+            entry
+        }
+    };
+    (@reference $entry:ident, $ty:ty) => {
+        $entry.with_reference::<$ty>()
+    };
+    (@generator $entry:ident, $ty:ty) => {
+        $entry.with_generator::<$ty>()
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Runs a [`Solution`] on an input, as in [`Entry::run`].
+type RunFn = fn(&str, Option<u8>) -> Result<Run>;
+
+/// A type-erased handle to a single day's [`Solution`].
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    pub id: PuzzleId,
+    /// Number of parts the puzzle has, either 1 or 2.
+    pub parts: u8,
+    normalize: Normalize,
+    run: RunFn,
+    reference: Option<RunFn>,
+    generate: Option<fn(&mut Rng, usize) -> String>,
+}
+
+/// Answers and timings from running a solution once.
+#[derive(Debug)]
+pub struct Run {
+    /// Time taken by [`Solution::parse`].
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// Result of running a single part.
+#[derive(Debug)]
+pub struct PartRun {
+    /// Part number, either 1 or 2.
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub time: Duration,
+}
+
+fn run<S: for<'a> Solution<'a>>(input: &str, part: Option<u8>) -> Result<Run> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let mut parts = Vec::with_capacity(2);
+    for n in 1..=S::PARTS {
+        if part.is_some_and(|p| p != n) {
+            continue;
+        }
+        let start = Instant::now();
+        let answer = match n {
+            1 => S::part1(&parsed).map(Into::into),
+            _ => S::part2(&parsed).map(Into::into),
+        };
+        let time = start.elapsed();
+        parts.push(PartRun {
+            part: n,
+            answer,
+            time,
+        });
+    }
+    Ok(Run { parse_time, parts })
+}
+
+impl Entry {
+    pub const fn new<S: for<'a> Solution<'a>>(id: PuzzleId) -> Entry {
+        let parts = S::PARTS;
+        assert!(parts == 1 || parts == 2, "puzzles must have 1 or 2 parts");
+        Entry {
+            id,
+            parts,
+            normalize: S::NORMALIZE,
+            run: run::<S>,
+            reference: None,
+            generate: None,
+        }
+    }
+
+    /// Registers a slower but obviously correct solution of the same puzzle, which
+    /// `aoc crosscheck` compares this one against.
+    pub const fn with_reference<R: for<'a> Solution<'a>>(self) -> Entry {
+        assert!(
+            R::PARTS == self.parts,
+            "reference solutions must have as many parts as the solution"
+        );
+        Entry {
+            reference: Some(run::<R>),
+            ..self
+        }
+    }
+
+    /// Registers a generator of random inputs for the puzzle.
+    pub const fn with_generator<G: InputGenerator>(self) -> Entry {
+        Entry {
+            generate: Some(G::generate),
+            ..self
+        }
+    }
+
+    pub fn has_reference(&self) -> bool {
+        self.reference.is_some()
+    }
+
+    pub fn has_generator(&self) -> bool {
+        self.generate.is_some()
+    }
+
+    /// Cleans up the input as the solution's [`Solution::NORMALIZE`] asks.
+    pub fn normalize<'i>(&self, input: &'i str) -> Cow<'i, str> {
+        crate::normalize(input, self.normalize)
+    }
+
+    /// Normalizes and parses the input once, then runs the given part, or every part if
+    /// `part` is `None`.
+    ///
+    /// Parts beyond [`Entry::parts`] are never run.
+    ///
+    /// Returns an error if the input can't be parsed. Errors from the parts themselves are
+    /// reported in [`PartRun::answer`].
+    pub fn run(&self, input: &str, part: Option<u8>) -> Result<Run> {
+        (self.run)(&self.normalize(input), part)
+    }
+
+    /// Like [`Entry::run`], but runs the reference solution. Returns `None` if the puzzle has
+    /// none.
+    pub fn run_reference(&self, input: &str, part: Option<u8>) -> Option<Result<Run>> {
+        let reference = self.reference?;
+        Some(reference(&self.normalize(input), part))
+    }
+
+    /// Generates a random input from `rng`, or returns `None` if the puzzle has no generator.
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        Some((self.generate?)(rng, size))
+    }
+}
+
+/// Finds the solution for the given puzzle.
+pub fn find(id: PuzzleId) -> Option<&'static Entry> {
+    ENTRIES.iter().find(|entry| entry.id == id)
+}
+
+/// Returns the solutions for the selected puzzles, sorted by date.
+pub fn select(selection: &Selection) -> impl Iterator<Item = &'static Entry> {
+    ENTRIES.iter().filter(|entry| selection.contains(entry.id))
+}
+
+/// Tests generated by `build.rs` from the sample inputs in `inputs/`.
+#[cfg(all(test, has_samples))]
+mod samples {
+    use pretty_assertions::assert_eq;
+
+    use crate::{Answer, PuzzleId};
+
+    /// Checks that the given part's answer for a sample input is `expected`.
+    fn check_sample(id: PuzzleId, input: &str, part: u8, expected: &str) {
+        let entry = super::find(id).unwrap();
+        assert!(part <= entry.parts, "{id} has no part {part}");
+        let mut run = entry.run(input, Some(part)).unwrap();
+        let answer = run.parts.pop().unwrap().answer.unwrap();
+        assert_eq!(expected.parse::<Answer>().unwrap(), answer);
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/samples.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn single_part_puzzle_only_runs_part_1() {
+        let entry = find(PuzzleId::new(2025, 12)).unwrap();
+        assert_eq!(1, entry.parts);
+        let run = entry.run("0:\n###\n##.\n##.\n\n4x4: 1\n", None).unwrap();
+        let parts: Vec<u8> = run.parts.iter().map(|part| part.part).collect();
+        assert_eq!(vec![1], parts);
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        for entry in ENTRIES.iter().filter(|entry| entry.has_generator()) {
+            for seed in 0..5 {
+                let input = entry.generate(&mut Rng::new(seed), 8).unwrap();
+                let run = entry.run(&input, None).unwrap_or_else(|err| {
+                    panic!("{}: seed {seed}: {err}\n{input}", entry.id);
+                });
+                for part in run.parts {
+                    if let Err(err) = part.answer
+                        && !err.is_not_implemented()
+                    {
+                        panic!(
+                            "{} part {}: seed {seed}: {err}\n{input}",
+                            entry.id, part.part
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
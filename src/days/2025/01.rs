@@ -0,0 +1,99 @@
+use crate::{InputGenerator, PuzzleError, Result, Rng, Solution, parse_at};
+
+pub struct Problem;
+
+fn input_to_offsets(input: &str) -> Result<Vec<i32>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let Some(dir) = line.chars().next() else {
+                return Err(PuzzleError::at_line(i, "empty line"));
+            };
+            let digits = &line[dir.len_utf8()..];
+            let distance: u32 = parse_at(i, line, digits)?;
+            let distance = i32::try_from(distance).map_err(|_| {
+                PuzzleError::at_substr(i, line, digits, format!("distance {distance} is too large"))
+            })?;
+            match dir {
+                'L' => Ok(-distance),
+                'R' => Ok(distance),
+                other => Err(PuzzleError::at(
+                    i,
+                    0,
+                    format!("invalid direction '{other}'"),
+                )),
+            }
+        })
+        .collect()
+}
+
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Vec<i32>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        input_to_offsets(input)
+    }
+
+    fn part1(offsets: &Self::Parsed) -> Result<Self::Output1> {
+        let mut dial = 50;
+        let mut count = 0;
+        for &offset in offsets {
+            dial = (dial + offset % 100).rem_euclid(100);
+            if dial == 0 {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(offsets: &Self::Parsed) -> Result<Self::Output2> {
+        let mut dial: i32 = 50;
+        let mut count: u32 = 0;
+        for &offset in offsets {
+            let full_rotations = (offset / 100).unsigned_abs();
+            count += full_rotations;
+            let offset_rem = offset % 100;
+            let dial_start = dial;
+            dial += offset_rem;
+            if (dial_start > 0 && dial <= 0) || dial >= 100 {
+                count += 1;
+            }
+            dial = dial.rem_euclid(100);
+        }
+        Ok(count)
+    }
+}
+
+/// Generates `size` rotations of up to 999 clicks, so that some turn the dial several times.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+                format!("{direction}{}\n", rng.range(1..1000))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn rejects_distances_beyond_i32() {
+        let err = Problem::parse("R5\nL3000000000\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 2: distance 3000000000 is too large",
+            err.to_string()
+        );
+        let offsets = Problem::parse("L2147483647\n").unwrap();
+        assert_eq!(21474836, Problem::part2(&offsets).unwrap());
+    }
+}
+
+register!(generator = Problem);
@@ -0,0 +1,215 @@
+use std::ops::RangeInclusive;
+
+use crate::{InputGenerator, PuzzleError, Result, Rng, Solution, parse_at, trace};
+
+pub struct Problem;
+
+/// Parses the comma-separated list of ranges, which is all on one line.
+fn input_to_ranges(input: &str) -> Result<Vec<RangeInclusive<u64>>> {
+    input
+        .trim()
+        .split(',')
+        .map(|field| {
+            let Some((start, end)) = field.split_once('-') else {
+                return Err(PuzzleError::at_substr(0, input, field, "expected a range"));
+            };
+            Ok(parse_at(0, input, start)?..=parse_at(0, input, end)?)
+        })
+        .collect()
+}
+
+fn pow10(exp: u32) -> u128 {
+    10u128.pow(exp)
+}
+
+fn digits(num: u64) -> u32 {
+    num.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// Sums the numbers in `range` with `len` digits which are a block of `block` digits repeated
+/// `len / block` times.
+fn sum_repeating(range: &RangeInclusive<u64>, len: u32, block: u32) -> u128 {
+    // These are the blocks without a leading zero multiplied by 1 followed by `block - 1`
+    // zeros, repeated: 11 for 2 blocks of 1 digit, 10101 for 3 blocks of 2 digits.
+    let multiplier: u128 = (0..len / block).map(|i| pow10(i * block)).sum();
+    let low = u128::from(*range.start()).max(pow10(len - 1));
+    let high = u128::from(*range.end()).min(pow10(len) - 1);
+    let first = low.div_ceil(multiplier).max(pow10(block - 1));
+    let last = (high / multiplier).min(pow10(block) - 1);
+    if first > last {
+        return 0;
+    }
+    (first + last) * (last - first + 1) / 2 * multiplier
+}
+
+/// Sums the numbers in `range` with `len` digits which are a block repeated at least twice.
+fn sum_repeating_any(range: &RangeInclusive<u64>, len: u32) -> u128 {
+    let blocks: Vec<u32> = (1..len)
+        .filter(|&block| len.is_multiple_of(block))
+        .collect();
+    // A number made of repeated blocks of length `b` is also made of repeated blocks of every
+    // length which is a multiple of `b` and divides `len`, so count each number only under its
+    // shortest block.
+    let mut shortest: Vec<u128> = Vec::with_capacity(blocks.len());
+    for (i, &block) in blocks.iter().enumerate() {
+        let shorter: u128 = blocks[..i]
+            .iter()
+            .zip(&shortest)
+            .filter(|&(&other, _)| block.is_multiple_of(other))
+            .map(|(_, sum)| sum)
+            .sum();
+        shortest.push(sum_repeating(range, len, block) - shorter);
+    }
+    shortest.iter().sum()
+}
+
+/// Sums `f(range, len)` over the ranges and the lengths of the numbers in them.
+fn sum_by_length(
+    ranges: &[RangeInclusive<u64>],
+    f: impl Fn(&RangeInclusive<u64>, u32) -> u128,
+) -> Result<u64> {
+    let sum: u128 = ranges
+        .iter()
+        .flat_map(|range| (digits(*range.start())..=digits(*range.end())).map(|len| f(range, len)))
+        .sum();
+    u64::try_from(sum).map_err(|_| PuzzleError::new("the sum of invalid IDs overflows a u64"))
+}
+
+fn is_invalid_1(num: u64) -> bool {
+    let s = format!("{num}");
+    if !s.len().is_multiple_of(2) {
+        return false;
+    }
+    let (left, right) = s.split_at(s.len() / 2);
+    left == right
+}
+
+fn is_invalid_2(num: u64) -> bool {
+    let s = format!("{num}");
+    for len in 1..=(s.len() / 2) {
+        if !s.len().is_multiple_of(len) {
+            continue;
+        }
+        let mut chunks = s.as_bytes().chunks(len);
+        if chunks
+            .next()
+            .is_some_and(|first| chunks.all(|chunk| chunk == first))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Vec<RangeInclusive<u64>>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        input_to_ranges(input)
+    }
+
+    fn part1(ranges: &Self::Parsed) -> Result<Self::Output1> {
+        sum_by_length(ranges, |range, len| {
+            if len.is_multiple_of(2) {
+                sum_repeating(range, len, len / 2)
+            } else {
+                0
+            }
+        })
+    }
+
+    fn part2(ranges: &Self::Parsed) -> Result<Self::Output2> {
+        sum_by_length(ranges, sum_repeating_any)
+    }
+}
+
+/// Checks every number in each range, which is too slow for ranges of more than a few
+/// million numbers.
+pub struct Reference;
+impl<'a> Solution<'a> for Reference {
+    type Parsed = Vec<RangeInclusive<u64>>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        input_to_ranges(input)
+    }
+
+    fn part1(ranges: &Self::Parsed) -> Result<Self::Output1> {
+        let mut sum = 0;
+        for range in ranges.iter().cloned() {
+            for num in range {
+                if is_invalid_1(num) {
+                    sum += num;
+                }
+            }
+        }
+        Ok(sum)
+    }
+
+    fn part2(ranges: &Self::Parsed) -> Result<Self::Output2> {
+        let mut sum = 0;
+        for range in ranges.iter().cloned() {
+            for num in range {
+                if is_invalid_2(num) {
+                    trace!("invalid id {num}");
+                    sum += num;
+                }
+            }
+        }
+        Ok(sum)
+    }
+}
+
+/// Generates `size` ranges of up to a thousand IDs, about half of which start just before a
+/// repeated block so that they contain invalid IDs.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let ranges: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let len = rng.range(1..13) as u32;
+                let start = if rng.chance(0.5) {
+                    let blocks: Vec<u32> = (1..len)
+                        .filter(|&block| len.is_multiple_of(block))
+                        .collect();
+                    let block = if blocks.is_empty() {
+                        len
+                    } else {
+                        blocks[rng.index(blocks.len())]
+                    };
+                    let first = rng.range(10u64.pow(block - 1)..10u64.pow(block));
+                    let repeated: u64 = first
+                        .to_string()
+                        .repeat((len / block) as usize)
+                        .parse()
+                        .unwrap();
+                    repeated.saturating_sub(rng.range(0..500))
+                } else {
+                    rng.range(10u64.pow(len - 1)..10u64.pow(len))
+                };
+                format!("{start}-{}", start + rng.range(0..1000))
+            })
+            .collect();
+        ranges.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn counts_each_invalid_id_once() {
+        // 1111 and 111111 repeat blocks of several lengths.
+        let ranges = [1100..=1200, 111_000..=112_000, 95..=115];
+        for range in ranges {
+            let expected = Reference::part2(&vec![range.clone()]).unwrap();
+            assert_eq!(expected, Problem::part2(&vec![range]).unwrap());
+        }
+    }
+}
+
+register!(reference = Reference, generator = Problem);
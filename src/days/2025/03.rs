@@ -0,0 +1,103 @@
+use crate::{InputGenerator, PuzzleError, Result, Rng, Solution, column_of};
+
+pub struct Problem;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let digits = line.trim();
+            let offset = column_of(line, digits);
+            digits
+                .char_indices()
+                .map(|(j, c)| {
+                    c.to_digit(10).ok_or_else(|| {
+                        PuzzleError::at(i, offset + j, format!("invalid battery '{c}'"))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns an iterator over the banks, checking that each contains at least `min_len` batteries.
+fn banks_of_len(banks: &[Vec<u32>], min_len: usize) -> impl Iterator<Item = Result<&[u32]>> {
+    banks.iter().enumerate().map(move |(i, bank)| {
+        if bank.len() < min_len {
+            return Err(PuzzleError::at_line(
+                i,
+                format!("expected at least {min_len} batteries, got {}", bank.len()),
+            ));
+        }
+        Ok(bank.as_slice())
+    })
+}
+
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Vec<Vec<u32>>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(banks: &Self::Parsed) -> Result<Self::Output1> {
+        let mut sum = 0;
+        for bank in banks_of_len(banks, 2) {
+            let bank = bank?;
+            let mut first_digit_i = 0;
+            for i in 1..(bank.len() - 1) {
+                if bank[i] > bank[first_digit_i] {
+                    first_digit_i = i;
+                }
+            }
+            let first_digit = bank[first_digit_i];
+            let second_digit = *bank[(first_digit_i + 1)..].iter().max().unwrap();
+            sum += (first_digit * 10 + second_digit) as u64;
+        }
+        Ok(sum)
+    }
+
+    fn part2(banks: &Self::Parsed) -> Result<Self::Output2> {
+        let mut sum = 0;
+        for bank in banks_of_len(banks, 12) {
+            let bank = bank?;
+            let mut chosen_indices = Vec::with_capacity(12);
+            for i in 0..12 {
+                let search_start = if i > 0 { chosen_indices[i - 1] + 1 } else { 0 }; // inclusive
+                let search_end = bank.len() - (11 - i); // exclusive
+                let (max_i, _) = bank
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .skip(search_start)
+                    .take(search_end - search_start)
+                    .max_by_key(|&(i, val)| (val, -(i as i32)))
+                    .unwrap();
+                chosen_indices.push(max_i);
+            }
+            for (i, bi) in chosen_indices.into_iter().enumerate() {
+                sum += (bank[bi] as u64) * 10u64.pow(12 - i as u32 - 1);
+            }
+        }
+        Ok(sum)
+    }
+}
+
+/// Generates `size` banks of 12 to 100 batteries, enough for part 2 to turn on 12 of them.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            for _ in 0..rng.range(12..101) {
+                input.push(char::from(b'0' + rng.range(1..10) as u8));
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+register!(generator = Problem);
@@ -0,0 +1,81 @@
+use crate::{
+    InputGenerator, Result, Rng, Solution,
+    grid::{Grid, Pos},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Paper,
+    Empty,
+}
+
+fn parse_input(input: &str) -> Result<Grid<Cell>> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(Cell::Empty),
+        '@' => Some(Cell::Paper),
+        _ => None,
+    })
+}
+
+/// Returns whether the paper at `pos` has fewer than 4 rolls of paper around it.
+fn is_accessible(grid: &Grid<Cell>, pos: Pos) -> bool {
+    grid.neighbors8(pos)
+        .filter(|&neighbor| grid[neighbor] == Cell::Paper)
+        .count()
+        < 4
+}
+
+/// Returns the positions of the paper which can be removed.
+fn accessible(grid: &Grid<Cell>) -> impl Iterator<Item = Pos> + '_ {
+    grid.iter()
+        .filter(|&(_, &cell)| cell == Cell::Paper)
+        .map(|(pos, _)| pos)
+        .filter(|&pos| is_accessible(grid, pos))
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Grid<Cell>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Self::Output1> {
+        Ok(accessible(grid).count())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Self::Output2> {
+        let mut grid = grid.clone();
+        let mut count = 0;
+        loop {
+            let removable: Vec<Pos> = accessible(&grid).collect();
+            if removable.is_empty() {
+                return Ok(count);
+            }
+            count += removable.len();
+            for pos in removable {
+                grid[pos] = Cell::Empty;
+            }
+        }
+    }
+}
+
+/// Generates a `size` by `size` grid, with paper in about 60% of the cells.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut input = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            for _ in 0..size {
+                input.push(if rng.chance(0.6) { '@' } else { '.' });
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+register!(generator = Problem);
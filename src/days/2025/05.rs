@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use crate::{InputGenerator, PuzzleError, Result, Rng, Solution, parse_at};
+use rangemap::RangeInclusiveSet;
+
+pub struct Input {
+    fresh_ranges: RangeInclusiveSet<u64>,
+    ingredients: Vec<u64>,
+}
+
+impl FromStr for Input {
+    type Err = PuzzleError;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines().enumerate();
+        let fresh_ranges: RangeInclusiveSet<u64> = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                let Some((l, r)) = line.split_once('-') else {
+                    return Err(PuzzleError::at_line(i, "expected a range"));
+                };
+                let (min, max) = (parse_at(i, line, l)?, parse_at(i, line, r)?);
+                Ok(min..=max)
+            })
+            .collect::<Result<_>>()?;
+        let ingredients = lines
+            .map(|(i, line)| parse_at(i, line, line))
+            .collect::<Result<_>>()?;
+        Ok(Input {
+            fresh_ranges,
+            ingredients,
+        })
+    }
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Input;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Self::Output1> {
+        let Input {
+            fresh_ranges,
+            ingredients,
+        } = input;
+        let mut fresh_count = 0;
+        for ingredient in ingredients {
+            if fresh_ranges.contains(ingredient) {
+                fresh_count += 1;
+            }
+        }
+        Ok(fresh_count)
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Self::Output2> {
+        Ok(input
+            .fresh_ranges
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .sum())
+    }
+}
+
+/// Generates `size` fresh ranges and `size` ingredient IDs, drawn from few enough IDs that
+/// ranges often overlap and ingredients are often fresh.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let ids = 1000 * size as u64;
+        let mut input = String::new();
+        for _ in 0..size {
+            let start = rng.range(1..ids);
+            input.push_str(&format!("{start}-{}\n", start + rng.range(0..ids / 10)));
+        }
+        input.push('\n');
+        for _ in 0..size {
+            input.push_str(&format!("{}\n", rng.range(1..ids)));
+        }
+        input
+    }
+}
+
+register!(generator = Problem);
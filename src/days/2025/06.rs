@@ -0,0 +1,205 @@
+use crate::{InputGenerator, Normalize, PuzzleError, Result, Rng, Solution, grid::Grid, parse_at};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Add,
+    Mul,
+}
+
+#[derive(Debug, Clone)]
+pub struct MathProblem {
+    numbers: Vec<u64>,
+    operation: Operation,
+}
+
+/// The worksheet, read both as humans do (part 1) and as cephalopods do (part 2).
+pub struct Worksheet {
+    human: Vec<MathProblem>,
+    cephalopod: Vec<MathProblem>,
+}
+
+fn parse_input_1(input: &str) -> Result<Vec<MathProblem>> {
+    let mut row_wise_matrix: Vec<Vec<u64>> = Vec::new();
+    let mut operations: Vec<Operation> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        match line.trim_start().chars().next() {
+            Some(c) if c.is_ascii_digit() => {
+                // Number line
+                let row = line
+                    .split_ascii_whitespace()
+                    .map(|num| parse_at(i, line, num))
+                    .collect::<Result<Vec<u64>>>()?;
+                if row_wise_matrix
+                    .first()
+                    .is_some_and(|first| first.len() != row.len())
+                {
+                    return Err(PuzzleError::at_line(i, "rows have different lengths"));
+                }
+                row_wise_matrix.push(row);
+            }
+            Some(_) => {
+                // Operation row
+                operations = line
+                    .split_ascii_whitespace()
+                    .map(|c| match c {
+                        "+" => Ok(Operation::Add),
+                        "*" => Ok(Operation::Mul),
+                        _ => Err(PuzzleError::at_substr(i, line, c, "invalid operation")),
+                    })
+                    .collect::<Result<_>>()?;
+            }
+            None => return Err(PuzzleError::at_line(i, "empty line")),
+        }
+    }
+    if row_wise_matrix.is_empty() {
+        return Err(PuzzleError::new("no number rows in input"));
+    }
+    Ok(transpose(row_wise_matrix, operations))
+}
+
+fn transpose(number_rows: Vec<Vec<u64>>, operations: Vec<Operation>) -> Vec<MathProblem> {
+    let mut number_columns: Vec<Vec<u64>> =
+        vec![Vec::with_capacity(number_rows.len()); number_rows[0].len()];
+    for row in number_rows {
+        for (i, num) in row.into_iter().enumerate() {
+            number_columns[i].push(num);
+        }
+    }
+    std::iter::zip(number_columns, operations)
+        .map(|(numbers, operation)| MathProblem { numbers, operation })
+        .collect()
+}
+
+fn parse_input_2(input: &str) -> Result<Vec<MathProblem>> {
+    let grid = Grid::parse(input, Some)?;
+    let mut problems = Vec::new();
+    let mut numbers = Vec::new();
+    for col in (0..grid.width()).rev() {
+        let mut num = 0;
+        let mut op = None;
+        for (i, &c) in grid.column(col).enumerate() {
+            match c {
+                ' ' => (),
+                c @ '0'..='9' => {
+                    let digit = c.to_digit(10).unwrap() as u64;
+                    num = num * 10 + digit;
+                }
+                '+' => op = Some(Operation::Add),
+                '*' => op = Some(Operation::Mul),
+                c => {
+                    return Err(PuzzleError::at(
+                        i,
+                        col,
+                        format!("unexpected character '{c}'"),
+                    ));
+                }
+            }
+        }
+        if num != 0 {
+            numbers.push(num);
+        }
+        if let Some(operation) = op {
+            let problem = MathProblem {
+                numbers: std::mem::take(&mut numbers),
+                operation,
+            };
+            problems.push(problem);
+        }
+    }
+    Ok(problems)
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Worksheet;
+    type Output1 = u64;
+    type Output2 = u64;
+    // Columns line up across rows, but editors trim the spaces at the end of short lines.
+    const NORMALIZE: Normalize = Normalize::GRID;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        Ok(Worksheet {
+            human: parse_input_1(input)?,
+            cephalopod: parse_input_2(input)?,
+        })
+    }
+
+    fn part1(worksheet: &Self::Parsed) -> Result<Self::Output1> {
+        let mut total = 0;
+        for column in &worksheet.human {
+            let do_op = match column.operation {
+                Operation::Add => |a, b| a + b,
+                Operation::Mul => |a, b| a * b,
+            };
+            total += column.numbers.iter().copied().reduce(do_op).unwrap_or(0);
+        }
+        Ok(total)
+    }
+
+    fn part2(worksheet: &Self::Parsed) -> Result<Self::Output2> {
+        let mut total = 0;
+        for problem in &worksheet.cephalopod {
+            let do_op = match problem.operation {
+                Operation::Add => |a, b| a + b,
+                Operation::Mul => |a, b| a * b,
+            };
+            total += problem.numbers.iter().copied().reduce(do_op).unwrap_or(0);
+        }
+        Ok(total)
+    }
+}
+
+/// Generates `size` problems of 2 to 4 numbers with up to 4 digits each. As in the puzzle, the
+/// numbers of a problem are all aligned either left or right in its columns.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let rows = rng.range(2..5) as usize;
+        let mut lines = vec![String::new(); rows + 1];
+        for problem in 0..size.max(1) {
+            if problem > 0 {
+                for line in &mut lines {
+                    line.push(' ');
+                }
+            }
+            let width = rng.range(1..5) as usize;
+            let widest = rng.index(rows);
+            let left = rng.chance(0.5);
+            for (row, line) in lines[..rows].iter_mut().enumerate() {
+                let digits = if row == widest {
+                    width
+                } else {
+                    rng.range(1..width as u64 + 1) as usize
+                };
+                let number: String = (0..digits)
+                    .map(|_| char::from(b'0' + rng.range(1..10) as u8))
+                    .collect();
+                if left {
+                    line.push_str(&format!("{number:<width$}"));
+                } else {
+                    line.push_str(&format!("{number:>width$}"));
+                }
+            }
+            let operation = if rng.chance(0.5) { '+' } else { '*' };
+            lines[rows].push_str(&format!("{operation:<width$}"));
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+
+    use super::Problem;
+    use crate::Solution as _;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn part2_sample1() {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+        let out = Problem::part2(&Problem::parse(input).unwrap()).unwrap();
+        assert_eq!(3263827, out);
+    }
+}
+
+register!(generator = Problem);
@@ -0,0 +1,127 @@
+use crate::{
+    InputGenerator, PuzzleError, Result, Rng, Solution,
+    grid::{Grid, Pos},
+};
+
+pub struct Manifold {
+    grid: Grid<char>,
+    start: Pos,
+}
+
+fn parse_input(input: &str) -> Result<Manifold> {
+    let grid = Grid::parse(input, |c| matches!(c, '.' | 'S' | '^').then_some(c))?;
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| PuzzleError::new("no start position in input"))?;
+    Ok(Manifold { grid, start })
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Manifold;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(manifold: &Self::Parsed) -> Result<Self::Output1> {
+        let mut splits = 0;
+        let width = manifold.grid.width();
+        let mut beams: Box<[bool]> = vec![false; width].into_boxed_slice();
+        let mut new_beams: Box<[bool]> = vec![false; width].into_boxed_slice();
+        for line in manifold.grid.rows() {
+            for (i, &c) in line.iter().enumerate() {
+                match c {
+                    'S' => new_beams[i] = true,
+                    '.' => {
+                        new_beams[i] |= beams[i];
+                    }
+                    '^' => {
+                        if beams[i] {
+                            splits += 1;
+                            if i > 0 {
+                                new_beams[i - 1] = true;
+                            }
+                            if i < width - 1 {
+                                new_beams[i + 1] = true;
+                            }
+                        }
+                    }
+                    _ => unreachable!("checked by parse_input()"),
+                }
+            }
+            std::mem::swap(&mut beams, &mut new_beams);
+            new_beams.iter_mut().for_each(|b| *b = false);
+        }
+        Ok(splits)
+    }
+
+    fn part2(manifold: &Self::Parsed) -> Result<Self::Output2> {
+        let grid = &manifold.grid;
+        // The number of timelines of a beam starting at each position.
+        let mut dp = Grid::new(grid.width(), grid.height(), 0u64);
+        for j in 0..grid.width() {
+            dp[(grid.height() - 1, j)] = 1;
+        }
+        for i in (0..(grid.height() - 1)).rev() {
+            for j in 0..grid.width() {
+                dp[(i, j)] = match grid[(i, j)] {
+                    '.' | 'S' => dp[(i + 1, j)],
+                    // Beams split off the side of the manifold leave no timelines.
+                    '^' => [-1, 1]
+                        .into_iter()
+                        .filter_map(|d_col| grid.offset((i + 1, j), (0, d_col)))
+                        .map(|pos| dp[pos])
+                        .sum(),
+                    _ => unreachable!("checked by parse_input()"),
+                }
+            }
+        }
+        Ok(dp[manifold.start])
+    }
+}
+
+/// Generates a manifold `2 * size + 1` columns wide and `2 * size` rows tall, starting in the
+/// middle of the top row. Splitters are on every other row, never next to each other or at
+/// the edges.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let width = 2 * size + 1;
+        let mut grid = Grid::new(width, 2 * size, '.');
+        grid[(0, width / 2)] = 'S';
+        for row in (2..2 * size).step_by(2) {
+            for col in 1..width - 1 {
+                if grid[(row, col - 1)] != '^' && rng.chance(0.3) {
+                    grid[(row, col)] = '^';
+                }
+            }
+        }
+        grid.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn splitters_at_the_edges() {
+        // The first splitter is never reached, and the others lose a beam off the side.
+        let cases = [
+            (".S.\n^..\n...\n", 0, 1),
+            ("S..\n^..\n...\n", 1, 1),
+            ("..S\n..^\n...\n", 1, 1),
+        ];
+        for (input, splits, timelines) in cases {
+            let manifold = Problem::parse(input).unwrap();
+            assert_eq!(splits, Problem::part1(&manifold).unwrap(), "{input:?}");
+            assert_eq!(timelines, Problem::part2(&manifold).unwrap(), "{input:?}");
+        }
+    }
+}
+
+register!(generator = Problem);
@@ -0,0 +1,121 @@
+use std::cmp::Reverse;
+
+use crate::{
+    InputGenerator, PuzzleError, Result, Rng, Solution, geom::Point3, parse_at,
+    union_find::UnionFind,
+};
+
+fn parse_input(input: &str) -> Result<Vec<Point3<u64>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_at(i, line, line))
+        .collect()
+}
+
+/// Constructs a weighted graph from the given point cloud.
+/// Returns an edge list.
+fn construct_graph(points: &[Point3<u64>]) -> Vec<(usize, usize, u64)> {
+    let mut graph = Vec::with_capacity(points.len().pow(2));
+    for i in 1..points.len() {
+        for j in 0..i {
+            let d2 = points[i].distance_squared(points[j]);
+            graph.push((i, j, d2));
+        }
+    }
+    graph
+}
+
+/// The junction boxes, along with every pair of them sorted by increasing distance.
+pub struct Playground {
+    points: Vec<Point3<u64>>,
+    edges: Vec<(usize, usize, u64)>,
+}
+
+/// Connects the `pairs` closest pairs of junction boxes and multiplies the sizes of the three
+/// largest circuits.
+fn largest_circuits(playground: &Playground, pairs: usize) -> Result<u64> {
+    let Playground { points, edges } = playground;
+    let closest = edges.get(..pairs).ok_or_else(|| {
+        PuzzleError::new(format!(
+            "needs at least {pairs} pairs of junction boxes, got {}",
+            edges.len()
+        ))
+    })?;
+    let mut circuits = UnionFind::new(points.len());
+    for &(i, j, _) in closest {
+        circuits.union(i, j);
+    }
+    let mut sizes: Vec<usize> = circuits.components().map(|members| members.len()).collect();
+    sizes.sort_unstable_by_key(|&size| Reverse(size));
+    let result: usize = sizes.into_iter().take(3).product();
+    Ok(result as u64)
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Playground;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        let points = parse_input(input)?;
+        let mut edges = construct_graph(&points);
+        edges.sort_unstable_by_key(|&(_, _, weight)| weight);
+        Ok(Playground { points, edges })
+    }
+
+    fn part1(playground: &Self::Parsed) -> Result<Self::Output1> {
+        largest_circuits(playground, 1000)
+    }
+
+    fn part2(playground: &Self::Parsed) -> Result<Self::Output2> {
+        let Playground { points, edges } = playground;
+        let mut circuits = UnionFind::new(points.len());
+        for &(i, j, _) in edges {
+            if circuits.union(i, j) && circuits.component_count() == 1 {
+                return Ok(points[i].x * points[j].x);
+            }
+        }
+        Err(PuzzleError::new("expected at least 2 junction boxes"))
+    }
+}
+
+/// Generates `size` junction boxes in a cube 100,000 units wide. At least 46 boxes are
+/// generated, since part 1 connects the 1000 closest pairs.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(46))
+            .map(|_| {
+                let [x, y, z] = [(); 3].map(|_| rng.range(0..100_000));
+                format!("{}\n", Point3::new(x, y, z))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const SAMPLE: &str = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n\
+                          466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n\
+                          216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n\
+                          970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689\n";
+
+    #[test]
+    fn sample() {
+        let playground = Problem::parse(SAMPLE).unwrap();
+        // The sample only connects its 10 closest pairs in part 1.
+        assert_eq!(40, largest_circuits(&playground, 10).unwrap());
+        assert_eq!(25272, Problem::part2(&playground).unwrap());
+        let err = Problem::part1(&playground).unwrap_err();
+        assert_eq!(
+            "needs at least 1000 pairs of junction boxes, got 190",
+            err.to_string()
+        );
+    }
+}
+
+register!(generator = Problem);
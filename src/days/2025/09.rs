@@ -0,0 +1,74 @@
+use crate::{
+    InputGenerator, PuzzleError, Result, Rng, Solution,
+    geom::{Point2, Rect},
+    parse_at,
+};
+
+fn parse_input(input: &str) -> Result<Vec<Point2<usize>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_at(i, line, line))
+        .collect()
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Vec<Point2<usize>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(points: &Self::Parsed) -> Result<Self::Output1> {
+        points
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| {
+                points[..i]
+                    .iter()
+                    .map(|&b| Rect::from_corners(*a, b).area())
+            })
+            .max()
+            .ok_or_else(|| PuzzleError::new("expected at least 2 red tiles"))
+    }
+
+    fn part2(_points: &Self::Parsed) -> Result<Self::Output2> {
+        Err(PuzzleError::not_implemented())
+    }
+}
+
+/// Generates a loop of `2 * size + 2` red tiles, each in line with the next: a staircase along
+/// the top, going left to right, then back along a flat bottom.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let steps = size.max(1);
+        let mut x = rng.range(0..1000);
+        let mut xs = vec![x];
+        for _ in 0..steps {
+            x += rng.range(1..1000);
+            xs.push(x);
+        }
+        let mut ys: Vec<u64> = Vec::with_capacity(steps);
+        while ys.len() < steps {
+            let y = rng.range(0..50_000);
+            if ys.last() != Some(&y) {
+                ys.push(y);
+            }
+        }
+        let bottom = 50_000 + rng.range(0..1000);
+
+        let mut points = Vec::with_capacity(2 * steps + 2);
+        for (i, &y) in ys.iter().enumerate() {
+            points.push(Point2::new(xs[i], y));
+            points.push(Point2::new(xs[i + 1], y));
+        }
+        points.push(Point2::new(xs[steps], bottom));
+        points.push(Point2::new(xs[0], bottom));
+        points.iter().map(|point| format!("{point}\n")).collect()
+    }
+}
+
+register!(generator = Problem);
@@ -0,0 +1,241 @@
+use crate::{InputGenerator, PuzzleError, Result, Rng, Solution, parse_at};
+use bitvec::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    expected_lights: BitBox,
+    buttons: Box<[Box<[usize]>]>,
+    #[allow(dead_code)] // Only needed for part 2
+    joltage: Box<[u64]>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_machine(i, line))
+        .collect()
+}
+
+fn parse_machine(line_index: usize, line: &str) -> Result<Machine> {
+    let mut lights = BitVec::new();
+    let mut buttons = Vec::new();
+    let mut joltage = Vec::new();
+    for part in line.split_whitespace() {
+        match part.chars().next() {
+            Some('[') => {
+                for c in part.trim_matches(['[', ']']).chars() {
+                    lights.push(c == '#');
+                }
+            }
+            Some('(') => {
+                let light_indices = part
+                    .trim_matches(['(', ')'])
+                    .split(',')
+                    .map(|i| parse_at(line_index, line, i))
+                    .collect::<Result<Box<[usize]>>>()?;
+                if light_indices.iter().any(|&i| i >= lights.len()) {
+                    return Err(PuzzleError::at_substr(
+                        line_index,
+                        line,
+                        part,
+                        format!("button refers to a light outside of 0..{}", lights.len()),
+                    ));
+                }
+                buttons.push(light_indices);
+            }
+            Some('{') => {
+                for num in part.trim_matches(['{', '}']).split(',') {
+                    joltage.push(parse_at(line_index, line, num)?);
+                }
+            }
+            _ => {
+                return Err(PuzzleError::at_substr(
+                    line_index,
+                    line,
+                    part,
+                    format!("invalid input part \"{part}\""),
+                ));
+            }
+        }
+    }
+    Ok(Machine {
+        expected_lights: lights.into_boxed_bitslice(),
+        buttons: buttons.into_boxed_slice(),
+        joltage: joltage.into_boxed_slice(),
+    })
+}
+
+/// Returns an iterator over all subsets of the elements in the given list.
+fn power_set<T>(set: &[T]) -> impl Iterator<Item = impl Iterator<Item = &T> + Clone> {
+    assert!(set.len() <= usize::BITS as usize);
+    (0..2usize.pow(set.len() as u32)).map(|mask| {
+        set.iter()
+            .enumerate()
+            .filter(move |(i, _)| ((mask >> i) & 1) == 1)
+            .map(|(_, item)| item)
+    })
+}
+
+/// Returns the lights which must be on and the lights toggled by each button as bit masks.
+fn masks(line: usize, machine: &Machine) -> Result<(u64, Vec<u64>)> {
+    if machine.expected_lights.len() > 64 {
+        return Err(PuzzleError::at_line(
+            line,
+            "machines have at most 64 lights",
+        ));
+    }
+    if machine.buttons.len() > 31 {
+        return Err(PuzzleError::at_line(
+            line,
+            "machines have at most 31 buttons",
+        ));
+    }
+    let expected = machine.expected_lights.iter_ones().map(|i| 1 << i).sum();
+    let buttons = machine
+        .buttons
+        .iter()
+        .map(|button| button.iter().fold(0, |mask, &i| mask ^ (1 << i)))
+        .collect();
+    Ok((expected, buttons))
+}
+
+/// Returns the fewest of at most 31 buttons to press to turn on the expected lights.
+fn fewest_presses(expected: u64, buttons: &[u64]) -> Option<u32> {
+    // Visit the subsets of buttons in Gray code order, so that each differs from the last by
+    // pressing or unpressing a single button.
+    let mut lights = 0;
+    let mut pressed: u32 = 0;
+    let mut fewest = (expected == 0).then_some(0);
+    for i in 1..1u32 << buttons.len() {
+        let button = i.trailing_zeros();
+        pressed ^= 1 << button;
+        lights ^= buttons[button as usize];
+        if lights == expected && fewest.is_none_or(|fewest| pressed.count_ones() < fewest) {
+            fewest = Some(pressed.count_ones());
+        }
+    }
+    fewest
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Vec<Machine>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Parsed) -> Result<Self::Output1> {
+        let mut total = 0;
+        for (line, machine) in machines.iter().enumerate() {
+            let (expected, buttons) = masks(line, machine)?;
+            total += fewest_presses(expected, &buttons).ok_or_else(|| {
+                PuzzleError::at_line(line, "no combination of buttons produces the lights")
+            })?;
+        }
+        Ok(total)
+    }
+
+    fn part2(_machines: &Self::Parsed) -> Result<Self::Output2> {
+        Err(PuzzleError::not_implemented())
+    }
+}
+
+/// Tries every subset of buttons, toggling the lights one by one.
+pub struct Reference;
+impl<'a> Solution<'a> for Reference {
+    type Parsed = Vec<Machine>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Parsed) -> Result<Self::Output1> {
+        let mut total = 0;
+        for (line, machine) in machines.iter().enumerate() {
+            let mut lights = bitbox![0; machine.expected_lights.len()];
+            let min_buttons = power_set(&machine.buttons)
+                .filter(|subset| {
+                    lights.fill(false);
+                    for button in subset.clone() {
+                        for &i in button {
+                            let bit = lights[i];
+                            lights.set(i, !bit);
+                        }
+                    }
+                    lights == machine.expected_lights
+                })
+                .map(|subset| subset.count())
+                .min()
+                .ok_or_else(|| {
+                    PuzzleError::at_line(line, "no combination of buttons produces the lights")
+                })?;
+            total += min_buttons;
+        }
+        Ok(total)
+    }
+
+    fn part2(_machines: &Self::Parsed) -> Result<Self::Output2> {
+        Err(PuzzleError::not_implemented())
+    }
+}
+
+/// Generates `size` machines of up to 10 lights and 12 buttons, whose expected lights are
+/// produced by some combination of the buttons.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let lights = rng.range(2..11) as usize;
+            let buttons: Vec<Vec<usize>> = (0..rng.range(1..13))
+                .map(|_| {
+                    let button: Vec<usize> = (0..lights).filter(|_| rng.chance(0.4)).collect();
+                    if button.is_empty() {
+                        vec![rng.index(lights)]
+                    } else {
+                        button
+                    }
+                })
+                .collect();
+            let mut expected = vec![false; lights];
+            for button in buttons.iter().filter(|_| rng.chance(0.5)) {
+                for &i in button {
+                    expected[i] = !expected[i];
+                }
+            }
+            input.push('[');
+            input.extend(expected.iter().map(|&on| if on { '#' } else { '.' }));
+            input.push(']');
+            for button in &buttons {
+                let lights: Vec<String> = button.iter().map(usize::to_string).collect();
+                input.push_str(&format!(" ({})", lights.join(",")));
+            }
+            let joltage: Vec<String> = (0..lights).map(|_| rng.range(0..200).to_string()).collect();
+            input.push_str(&format!(" {{{}}}\n", joltage.join(",")));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+
+    use super::Problem;
+    use crate::Solution as _;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn part1_sample1() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n";
+        let actual = Problem::part1(&Problem::parse(input).unwrap()).unwrap();
+        assert_eq!(2, actual);
+    }
+}
+
+register!(reference = Reference, generator = Problem);
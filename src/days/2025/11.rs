@@ -0,0 +1,55 @@
+use crate::{InputGenerator, Result, Rng, Solution, graph::Graph};
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Graph;
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        Graph::parse_directed(input)
+    }
+
+    fn part1(graph: &Self::Parsed) -> Result<Self::Output1> {
+        graph.count_paths(graph.node("you")?, graph.node("out")?, &[])
+    }
+
+    fn part2(graph: &Self::Parsed) -> Result<Self::Output2> {
+        let required = [graph.node("dac")?, graph.node("fft")?];
+        graph.count_paths(graph.node("svr")?, graph.node("out")?, &required)
+    }
+}
+
+/// Generates a graph of `size` devices besides `svr`, `you`, `dac`, `fft` and `out`. Devices
+/// only connect to devices after them in a random order starting at `svr`, so the graph has
+/// no cycles, but the number of paths grows quickly with `size`.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names: Vec<String> = ["you", "dac", "fft"].map(String::from).to_vec();
+        while names.len() < size + 3 {
+            let name: String = (0..3)
+                .map(|_| char::from(b'a' + rng.range(0..26) as u8))
+                .collect();
+            if !names.contains(&name) && !["svr", "out"].contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+        rng.shuffle(&mut names);
+        names.insert(0, "svr".to_string());
+        names.push("out".to_string());
+
+        let devices = names.len() - 1;
+        let mut input = String::new();
+        for (i, name) in names[..devices].iter().enumerate() {
+            let mut outputs: Vec<&str> = (0..rng.range(1..4))
+                .map(|_| names[i + 1 + rng.index(devices - i)].as_str())
+                .collect();
+            outputs.sort_unstable();
+            outputs.dedup();
+            input.push_str(&format!("{name}: {}\n", outputs.join(" ")));
+        }
+        input
+    }
+}
+
+register!(generator = Problem);
@@ -0,0 +1,193 @@
+use std::convert::Infallible;
+
+use crate::{InputGenerator, PuzzleError, Result, Rng, Solution, column_of, debug, parse_at};
+
+#[derive(Debug)]
+pub struct Input {
+    shapes: Box<[Shape]>,
+    regions: Box<[Region]>,
+}
+
+type Shape = [[bool; 3]; 3];
+
+#[derive(Debug)]
+struct Region {
+    width: u64,
+    length: u64,
+    presents: Box<[u64]>,
+}
+
+fn parse_input(input: &str) -> Result<Input> {
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    // Splitting always yields at least one section
+    let (regions, shapes) = sections.split_last().unwrap();
+    let shapes = shapes
+        .iter()
+        .map(|section| parse_section(input, section, parse_shape))
+        .collect::<Result<Vec<Shape>>>()?
+        .into_boxed_slice();
+    let regions = parse_section(input, regions, parse_regions)?;
+    if let Some(i) = regions.iter().position(|r| r.presents.len() > shapes.len()) {
+        return Err(PuzzleError::new(format!(
+            "region {} refers to more than {} shapes",
+            i + 1,
+            shapes.len()
+        )));
+    }
+    Ok(Input { shapes, regions })
+}
+
+/// Parses `section`, which must be a substring of `input`, adjusting the line number of any
+/// error so that it refers to `input`.
+fn parse_section<T>(input: &str, section: &str, parse: fn(&str) -> Result<T>) -> Result<T> {
+    let offset = input[..column_of(input, section)].matches('\n').count();
+    parse(section).map_err(|err| PuzzleError {
+        line: err.line.map(|line| line + offset),
+        ..err
+    })
+}
+
+fn parse_shape(input: &str) -> Result<Shape> {
+    let mut shape = [[false; 3]; 3];
+    for (i, line) in input.lines().skip(1).enumerate() {
+        if i >= 3 || line.len() > 3 {
+            return Err(PuzzleError::at_line(i + 1, "shapes must be at most 3x3"));
+        }
+        for (j, c) in line.chars().enumerate() {
+            shape[i][j] = match c {
+                '#' => true,
+                '.' => false,
+                other => {
+                    return Err(PuzzleError::at(
+                        i + 1,
+                        j,
+                        format!("invalid character in shape: '{other}'"),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(shape)
+}
+
+fn parse_regions(input: &str) -> Result<Box<[Region]>> {
+    let mut regions = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let Some((size, rest)) = line.split_once(": ") else {
+            return Err(PuzzleError::at_line(i, "expected ': '"));
+        };
+        let Some((width_str, length_str)) = size.split_once('x') else {
+            return Err(PuzzleError::at_substr(
+                i,
+                line,
+                size,
+                "expected a size like 4x4",
+            ));
+        };
+        let (width, length) = (
+            parse_at(i, line, width_str)?,
+            parse_at(i, line, length_str)?,
+        );
+        let presents = rest
+            .trim()
+            .split(' ')
+            .map(|count| parse_at(i, line, count))
+            .collect::<Result<Vec<u64>>>()?
+            .into_boxed_slice();
+        regions.push(Region {
+            width,
+            length,
+            presents,
+        });
+    }
+    Ok(regions.into_boxed_slice())
+}
+
+fn region_is_impossible(region: &Region, shapes: &[Shape]) -> bool {
+    let region_area = region.width * region.length;
+    let shapes_area: u64 = region
+        .presents
+        .iter()
+        .enumerate()
+        .map(|(i, count)| count * area_of_shape(&shapes[i]) as u64)
+        .sum();
+    shapes_area > region_area
+}
+
+fn region_is_trivially_possible(region: &Region) -> bool {
+    let can_fit_shapes = (region.width / 3) * (region.length / 3);
+    can_fit_shapes >= region.presents.iter().sum()
+}
+
+fn area_of_shape(shape: &Shape) -> u8 {
+    shape
+        .iter()
+        .map(|row| row.iter().filter(|cell| **cell).count() as u8)
+        .sum()
+}
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = Input;
+    type Output1 = usize;
+    type Output2 = Infallible;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Self::Output1> {
+        let impossible = input
+            .regions
+            .iter()
+            .filter(|region| region_is_impossible(region, &input.shapes))
+            .count();
+        let easy = input
+            .regions
+            .iter()
+            .filter(|region| region_is_trivially_possible(region))
+            .count();
+        debug!(
+            "{} regions: {impossible} impossible, {easy} trivially possible",
+            input.regions.len()
+        );
+        Ok(easy)
+    }
+}
+
+/// Generates six shapes of 5 to 7 cells, followed by `size` regions between 4 and 50 units wide
+/// and long.
+impl InputGenerator for Problem {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const SHAPES: usize = 6;
+        let mut sections = Vec::with_capacity(SHAPES + 1);
+        for i in 0..SHAPES {
+            let mut cells: Vec<usize> = (0..9).collect();
+            rng.shuffle(&mut cells);
+            let filled = &cells[..rng.range(5..8) as usize];
+            let mut section = format!("{i}:");
+            for cell in 0..9 {
+                if cell % 3 == 0 {
+                    section.push('\n');
+                }
+                section.push(if filled.contains(&cell) { '#' } else { '.' });
+            }
+            sections.push(section);
+        }
+        let regions: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let (width, length) = (rng.range(4..51), rng.range(4..51));
+                let presents: Vec<String> = (0..SHAPES)
+                    .map(|_| rng.range(0..width * length / 30 + 1).to_string())
+                    .collect();
+                format!("{width}x{length}: {}", presents.join(" "))
+            })
+            .collect();
+        sections.push(regions.join("\n"));
+        sections.join("\n\n") + "\n"
+    }
+}
+
+register!(generator = Problem);
@@ -0,0 +1,20 @@
+use crate::{PuzzleError, Result, Solution};
+
+pub struct Problem;
+impl<'a> Solution<'a> for Problem {
+    type Parsed = &'a str;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Parsed) -> Result<Self::Output1> {
+        Err(PuzzleError::not_implemented())
+    }
+
+    fn part2(_input: &Self::Parsed) -> Result<Self::Output2> {
+        Err(PuzzleError::not_implemented())
+    }
+}
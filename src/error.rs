@@ -0,0 +1,153 @@
+use std::fmt::{self, Display};
+
+/// Result type returned by [`Solution`](crate::Solution) methods.
+pub type Result<T, E = PuzzleError> = std::result::Result<T, E>;
+
+/// What kind of failure a [`PuzzleError`] is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The puzzle couldn't be solved, usually because the input is malformed.
+    #[default]
+    Failed,
+    /// The part hasn't been implemented yet. Runners report this separately from failures.
+    NotImplemented,
+}
+
+/// Error produced when a puzzle cannot be solved, usually because the input is malformed.
+///
+/// Line and column numbers are 1-based and refer to the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleError {
+    pub kind: ErrorKind,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl PuzzleError {
+    /// Creates an error which isn't associated with a position in the input.
+    pub fn new(message: impl Display) -> PuzzleError {
+        PuzzleError {
+            kind: ErrorKind::Failed,
+            line: None,
+            column: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates an error pointing at a whole line of the input.
+    ///
+    /// `line` is a 0-based index, as produced by [`Iterator::enumerate`].
+    pub fn at_line(line: usize, message: impl Display) -> PuzzleError {
+        PuzzleError {
+            line: Some(line + 1),
+            ..PuzzleError::new(message)
+        }
+    }
+
+    /// Creates an error pointing at a character in the input.
+    ///
+    /// `line` and `column` are 0-based indices, as produced by [`Iterator::enumerate`].
+    pub fn at(line: usize, column: usize, message: impl Display) -> PuzzleError {
+        PuzzleError {
+            line: Some(line + 1),
+            column: Some(column + 1),
+            ..PuzzleError::new(message)
+        }
+    }
+
+    /// Creates the error returned by parts which haven't been written yet.
+    pub fn not_implemented() -> PuzzleError {
+        PuzzleError {
+            kind: ErrorKind::NotImplemented,
+            ..PuzzleError::new("not implemented")
+        }
+    }
+
+    pub fn is_not_implemented(&self) -> bool {
+        self.kind == ErrorKind::NotImplemented
+    }
+
+    /// Creates an error pointing at `part`, which must be a substring of `line`.
+    ///
+    /// `line_index` is 0-based.
+    pub fn at_substr(
+        line_index: usize,
+        line: &str,
+        part: &str,
+        message: impl Display,
+    ) -> PuzzleError {
+        PuzzleError::at(line_index, column_of(line, part), message)
+    }
+
+    /// Sets the line of this error if it doesn't already have one.
+    ///
+    /// `line` is a 0-based index.
+    pub fn or_line(mut self, line: usize) -> PuzzleError {
+        self.line.get_or_insert(line + 1);
+        self
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => (),
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+/// Returns the 0-based byte column at which `part` starts within `line`.
+///
+/// # Panics
+///
+/// Panics if `part` is not a substring of `line`.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    assert!(
+        offset <= line.len() && offset + part.len() <= line.len(),
+        "part is not a substring of line"
+    );
+    offset
+}
+
+/// Parses `part`, which must be a substring of `line`, reporting failures at its position.
+///
+/// `line_index` is 0-based.
+pub fn parse_at<T>(line_index: usize, line: &str, part: &str) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    part.parse()
+        .map_err(|err| PuzzleError::at_substr(line_index, line, part, format!("{err}: {part:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display_includes_position() {
+        let line = "L68 R3x";
+        let err = parse_at::<u32>(2, line, &line[5..]).unwrap_err();
+        assert_eq!(
+            "line 3, column 6: invalid digit found in string: \"3x\"",
+            err.to_string()
+        );
+        assert_eq!("line 1: oops", PuzzleError::at_line(0, "oops").to_string());
+        assert_eq!("oops", PuzzleError::new("oops").to_string());
+    }
+
+    #[test]
+    fn or_line_keeps_existing_line() {
+        assert_eq!(Some(4), PuzzleError::new("x").or_line(3).line);
+        assert_eq!(Some(1), PuzzleError::at_line(0, "x").or_line(3).line);
+    }
+}
@@ -0,0 +1,300 @@
+//! Points, vectors and axis-aligned boxes in two and three dimensions.
+//!
+//! Points double as vectors, so `b - a` is the vector from `a` to `b`. Boxes include both of
+//! their corners, as puzzles usually place them on a grid of tiles or cubes: the box from
+//! `(1, 1)` to `(2, 2)` covers 4 tiles.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A number usable as a coordinate.
+pub trait Coord:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + FromStr
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Returns the distance between two coordinates, `|self - other|`.
+    fn distance(self, other: Self) -> Self;
+}
+
+macro_rules! unsigned_coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+
+            fn distance(self, other: $t) -> $t {
+                self.abs_diff(other)
+            }
+        }
+    )*};
+}
+
+macro_rules! signed_coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+
+            fn distance(self, other: $t) -> $t {
+                (self - other).abs()
+            }
+        }
+    )*};
+}
+
+unsigned_coord!(u8, u16, u32, u64, u128, usize);
+signed_coord!(i8, i16, i32, i64, i128, isize);
+
+/// Error returned when parsing a point fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError(String);
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ParsePointError {}
+
+/// Parses exactly `N` comma-separated coordinates.
+fn parse_coords<T: Coord, const N: usize>(s: &str) -> Result<[T; N], ParsePointError>
+where
+    T::Err: Display,
+{
+    let parts: Vec<&str> = s.split(',').map(str::trim).collect();
+    if parts.len() != N {
+        return Err(ParsePointError(format!(
+            "expected {N} coordinates, got {}",
+            parts.len()
+        )));
+    }
+    let mut coords = [T::ZERO; N];
+    for (coord, part) in coords.iter_mut().zip(parts) {
+        *coord = part
+            .parse()
+            .map_err(|err| ParsePointError(format!("{err}")))?;
+    }
+    Ok(coords)
+}
+
+/// Implements the vector arithmetic and formatting shared by [`Point2`] and [`Point3`].
+macro_rules! point {
+    ($name:ident, $n:literal, $($field:ident),+) => {
+        impl<T: Coord> $name<T> {
+            pub const ORIGIN: $name<T> = $name { $($field: T::ZERO),+ };
+
+            pub const fn new($($field: T),+) -> $name<T> {
+                $name { $($field),+ }
+            }
+
+            /// Returns the distance to `other` moving along the axes.
+            pub fn manhattan(self, other: $name<T>) -> T {
+                T::ZERO $(+ self.$field.distance(other.$field))+
+            }
+
+            /// Returns the distance to `other` moving along the axes and diagonals, like a king
+            /// in chess.
+            pub fn chebyshev(self, other: $name<T>) -> T {
+                let mut max = T::ZERO;
+                $(max = max.max(self.$field.distance(other.$field));)+
+                max
+            }
+
+            /// Returns the square of the straight-line distance to `other`, which is an
+            /// integer unlike the distance itself.
+            pub fn distance_squared(self, other: $name<T>) -> T {
+                let mut sum = T::ZERO;
+                $(
+                    let d = self.$field.distance(other.$field);
+                    sum = sum + d * d;
+                )+
+                sum
+            }
+        }
+
+        impl<T: Coord> Add for $name<T> {
+            type Output = $name<T>;
+
+            fn add(self, other: $name<T>) -> $name<T> {
+                $name { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $name<T> {
+            type Output = $name<T>;
+
+            fn sub(self, other: $name<T>) -> $name<T> {
+                $name { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: $name<T>) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: $name<T>) {
+                *self = *self - other;
+            }
+        }
+
+        /// Scales a vector.
+        impl<T: Coord> Mul<T> for $name<T> {
+            type Output = $name<T>;
+
+            fn mul(self, factor: T) -> $name<T> {
+                $name { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Coord + Neg<Output = T>> Neg for $name<T> {
+            type Output = $name<T>;
+
+            fn neg(self) -> $name<T> {
+                $name { $($field: -self.$field),+ }
+            }
+        }
+
+        /// Parses comma-separated coordinates, like `1,2`.
+        impl<T: Coord> FromStr for $name<T>
+        where
+            T::Err: Display,
+        {
+            type Err = ParsePointError;
+
+            fn from_str(s: &str) -> Result<$name<T>, ParsePointError> {
+                let [$($field),+] = parse_coords::<T, $n>(s)?;
+                Ok($name { $($field),+ })
+            }
+        }
+
+        /// Formats the coordinates as they are parsed, like `1,2`.
+        impl<T: Coord + Display> Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coords = [$(self.$field.to_string()),+];
+                f.write_str(&coords.join(","))
+            }
+        }
+    };
+}
+
+/// A point or vector in two dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point or vector in three dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+point!(Point2, 2, x, y);
+point!(Point3, 3, x, y, z);
+
+/// Implements the methods shared by [`Rect`] and [`Cuboid`].
+macro_rules! aabb {
+    ($name:ident, $point:ident, $size:ident, $($field:ident),+) => {
+        impl<T: Coord> $name<T> {
+            /// Returns the box with `a` and `b` as opposite corners.
+            pub fn from_corners(a: $point<T>, b: $point<T>) -> $name<T> {
+                $name {
+                    min: $point { $($field: a.$field.min(b.$field)),+ },
+                    max: $point { $($field: a.$field.max(b.$field)),+ },
+                }
+            }
+
+            pub fn contains(&self, point: $point<T>) -> bool {
+                true $(&& (self.min.$field..=self.max.$field).contains(&point.$field))+
+            }
+
+            /// Returns the box covered by both boxes, if they overlap.
+            pub fn intersection(&self, other: &$name<T>) -> Option<$name<T>> {
+                let min = $point { $($field: self.min.$field.max(other.min.$field)),+ };
+                let max = $point { $($field: self.max.$field.min(other.max.$field)),+ };
+                (true $(&& min.$field <= max.$field)+).then_some($name { min, max })
+            }
+
+            /// Returns the number of tiles or cubes the box covers.
+            pub fn $size(&self) -> T {
+                T::ONE $(* (self.max.$field - self.min.$field + T::ONE))+
+            }
+        }
+    };
+}
+
+/// An axis-aligned rectangle, including both corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+/// An axis-aligned cuboid, including both corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+aabb!(Rect, Point2, area, x, y);
+aabb!(Cuboid, Point3, volume, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn metrics() {
+        let a = Point3::new(1u64, 5, 2);
+        let b = Point3::new(4, 1, 2);
+        assert_eq!(7, a.manhattan(b));
+        assert_eq!(4, a.chebyshev(b));
+        assert_eq!(25, a.distance_squared(b));
+        assert_eq!(
+            Point2::new(-2, 3),
+            -Point2::new(1, -2) * 2 + Point2::new(0, -1)
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!(Ok(Point3::new(162, 817, 812)), "162,817,812".parse());
+        assert_eq!("7,-1", Point2::new(7, -1).to_string());
+        let err = "1,2".parse::<Point3<u64>>().unwrap_err();
+        assert_eq!("expected 3 coordinates, got 2", err.to_string());
+        assert!("1,x".parse::<Point2<u64>>().is_err());
+    }
+
+    #[test]
+    fn rect_area_and_intersection() {
+        let a = Rect::from_corners(Point2::new(11u64, 1), Point2::new(2, 5));
+        assert_eq!(50, a.area());
+        let b = Rect::from_corners(Point2::new(10, 5), Point2::new(20, 9));
+        let overlap = a.intersection(&b).unwrap();
+        assert_eq!(
+            Rect::from_corners(Point2::new(10, 5), Point2::new(11, 5)),
+            overlap
+        );
+        assert!(overlap.contains(Point2::new(11, 5)));
+        assert_eq!(
+            None,
+            a.intersection(&Rect::from_corners(Point2::new(12, 0), Point2::new(13, 0)))
+        );
+        let cube = Cuboid::from_corners(Point3::ORIGIN, Point3::new(2i32, 2, 2));
+        assert_eq!(27, cube.volume());
+    }
+}
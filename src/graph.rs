@@ -0,0 +1,299 @@
+//! Graphs whose nodes have names, stored as adjacency lists of dense node ids.
+
+use std::{borrow::Borrow, fmt::Display, hash::Hash};
+
+use crate::{PuzzleError, Result, interner::Interner};
+
+/// A directed or undirected graph with named nodes.
+///
+/// Nodes are given ids in the order they are first seen, and edges are stored both ways so
+/// that a node's predecessors can be found as quickly as its successors.
+#[derive(Debug, Clone)]
+pub struct Graph<K = String> {
+    names: Interner<K>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    directed: bool,
+}
+
+impl<K: Eq + Hash + Clone> Graph<K> {
+    pub fn directed() -> Graph<K> {
+        Graph {
+            names: Interner::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+            directed: true,
+        }
+    }
+
+    /// Creates a graph where every edge can be followed both ways.
+    pub fn undirected() -> Graph<K> {
+        Graph {
+            directed: false,
+            ..Graph::directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the id of the node named `name`, adding it if it doesn't exist yet.
+    pub fn add_node(&mut self, name: K) -> usize {
+        let id = self.names.intern(name);
+        if id == self.successors.len() {
+            self.successors.push(Vec::new());
+            self.predecessors.push(Vec::new());
+        }
+        id
+    }
+
+    /// Adds an edge between the nodes named `from` and `to`, adding them first if needed.
+    /// Returns their ids.
+    pub fn add_edge(&mut self, from: K, to: K) -> (usize, usize) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.add_edge_ids(from, to);
+        (from, to)
+    }
+
+    /// Adds an edge between two existing nodes.
+    pub fn add_edge_ids(&mut self, from: usize, to: usize) {
+        self.successors[from].push(to);
+        self.predecessors[to].push(from);
+        if !self.directed && from != to {
+            self.successors[to].push(from);
+            self.predecessors[from].push(to);
+        }
+    }
+
+    /// Returns the id of the node named `name`, or an error if there is none.
+    pub fn node<Q>(&self, name: &Q) -> Result<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + Display + ?Sized,
+    {
+        self.names
+            .get(name)
+            .ok_or_else(|| PuzzleError::new(format!("no node named \"{name}\"")))
+    }
+
+    pub fn name(&self, node: usize) -> &K {
+        self.names.key(node)
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.successors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.successors.is_empty()
+    }
+
+    /// Returns the nodes reachable from `node` over a single edge, in the order the edges were
+    /// added.
+    pub fn successors(&self, node: usize) -> &[usize] {
+        &self.successors[node]
+    }
+
+    /// Returns the nodes with an edge to `node`, in the order the edges were added.
+    pub fn predecessors(&self, node: usize) -> &[usize] {
+        &self.predecessors[node]
+    }
+
+    /// Returns every node's successors, indexed by node id.
+    pub fn adjacency(&self) -> &[Vec<usize>] {
+        &self.successors
+    }
+
+    /// Returns the nodes reachable from `start`, including itself, ordered so that each node
+    /// comes before its successors. Returns an error if they contain a cycle.
+    pub fn topological_order(&self, start: usize) -> Result<Vec<usize>> {
+        let mut reachable = vec![false; self.len()];
+        reachable[start] = true;
+        let mut stack = vec![start];
+        let mut in_degree = vec![0; self.len()];
+        while let Some(node) = stack.pop() {
+            for &next in self.successors(node) {
+                in_degree[next] += 1;
+                if !reachable[next] {
+                    reachable[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        let cycle = || PuzzleError::new("graph contains a cycle");
+        if in_degree[start] > 0 {
+            return Err(cycle());
+        }
+        let mut order = Vec::new();
+        let mut ready = vec![start];
+        while let Some(node) = ready.pop() {
+            order.push(node);
+            for &next in self.successors(node) {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+        // Nodes on a cycle never run out of predecessors, so they are left out.
+        if order.len() < reachable.iter().filter(|&&r| r).count() {
+            return Err(cycle());
+        }
+        Ok(order)
+    }
+
+    /// Counts the paths from `start` to `end` which visit every node in `required`, in any
+    /// order. Returns an error if a cycle can be reached from `start`, since there would be
+    /// infinitely many paths, or if the count doesn't fit in a `u128`.
+    ///
+    /// The nodes are visited in topological order, counting the paths reaching each node for
+    /// each subset of the required nodes they have visited, so this takes `O(2^k (V + E))`
+    /// time for `k` required nodes.
+    pub fn count_paths(&self, start: usize, end: usize, required: &[usize]) -> Result<u128> {
+        /// Limits the table of counts, which has an entry for each subset of the required nodes
+        /// at each node.
+        const MAX_REQUIRED: usize = 16;
+        if required.len() > MAX_REQUIRED {
+            return Err(PuzzleError::new(format!(
+                "can't count paths through more than {MAX_REQUIRED} required nodes"
+            )));
+        }
+        let mut bits = vec![0usize; self.len()];
+        for (i, &node) in required.iter().enumerate() {
+            bits[node] |= 1 << i;
+        }
+        let all = (1 << required.len()) - 1;
+
+        let order = self.topological_order(start)?;
+        let mut index = vec![usize::MAX; self.len()];
+        for (i, &node) in order.iter().enumerate() {
+            index[node] = i;
+        }
+        // `counts[index[node] * masks + mask]` is the number of paths from `start` to `node`
+        // which visit exactly the required nodes in `mask`.
+        let masks = all + 1;
+        let mut counts = vec![0u128; order.len() * masks];
+        counts[bits[start]] = 1;
+        for &node in &order {
+            let from = index[node] * masks;
+            for mask in 0..masks {
+                let count = counts[from + mask];
+                if count == 0 {
+                    continue;
+                }
+                for &next in self.successors(node) {
+                    let to = index[next] * masks + (mask | bits[next]);
+                    counts[to] = counts[to]
+                        .checked_add(count)
+                        .ok_or_else(|| PuzzleError::new("path count overflows u128"))?;
+                }
+            }
+        }
+        Ok(match index[end] {
+            usize::MAX => 0,
+            i => counts[i * masks + all],
+        })
+    }
+}
+
+impl Graph<String> {
+    /// Parses a directed graph with one node per line, followed by the nodes it has edges to,
+    /// like `aaa: bbb ccc`. Nodes which only appear after a colon are added too.
+    pub fn parse_directed(input: &str) -> Result<Graph<String>> {
+        Graph::directed().extend_parse(input)
+    }
+
+    /// Parses an undirected graph written like [`Graph::parse_directed`]'s input.
+    pub fn parse_undirected(input: &str) -> Result<Graph<String>> {
+        Graph::undirected().extend_parse(input)
+    }
+
+    /// Adds the nodes and edges parsed from `input` to the graph.
+    fn extend_parse(mut self, input: &str) -> Result<Graph<String>> {
+        for (i, line) in input.lines().enumerate() {
+            let Some((name, rest)) = line.split_once(':') else {
+                return Err(PuzzleError::at_line(i, "expected ':'"));
+            };
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(PuzzleError::at_line(i, "expected a node name before ':'"));
+            }
+            let from = self.add_node(name.to_string());
+            for to in rest.split_whitespace() {
+                let to = self.add_node(to.to_string());
+                self.add_edge_ids(from, to);
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_directed() {
+        let graph = Graph::parse_directed("you: bbb ccc\nbbb: out\nccc: out eee\n").unwrap();
+        assert_eq!(5, graph.len());
+        let [you, bbb, ccc, out, eee] =
+            ["you", "bbb", "ccc", "out", "eee"].map(|name| graph.node(name).unwrap());
+        assert_eq!(&[bbb, ccc], graph.successors(you));
+        assert_eq!(&[bbb, ccc], graph.predecessors(out));
+        assert!(graph.successors(eee).is_empty());
+        assert_eq!("ccc", graph.name(ccc));
+        assert_eq!(
+            "no node named \"dac\"",
+            graph.node("dac").unwrap_err().to_string()
+        );
+        let err = Graph::parse_directed("you: out\nbbb out\n").unwrap_err();
+        assert_eq!("line 2: expected ':'", err.to_string());
+    }
+
+    #[test]
+    fn count_paths_through_required_nodes() {
+        let input = "svr: aaa bbb\naaa: fft\nfft: ccc\nbbb: tty\ntty: ccc\nccc: ddd eee\n\
+                     ddd: hub\nhub: fff\neee: dac\ndac: fff\nfff: ggg hhh\nggg: out\nhhh: out\n";
+        let graph = Graph::parse_directed(input).unwrap();
+        let [svr, out, fft, dac] =
+            ["svr", "out", "fft", "dac"].map(|name| graph.node(name).unwrap());
+        assert_eq!(8, graph.count_paths(svr, out, &[]).unwrap());
+        assert_eq!(4, graph.count_paths(svr, out, &[fft]).unwrap());
+        assert_eq!(2, graph.count_paths(svr, out, &[dac, fft]).unwrap());
+        assert_eq!(0, graph.count_paths(out, svr, &[]).unwrap());
+        assert_eq!(1, graph.count_paths(fft, fft, &[fft]).unwrap());
+
+        let cyclic = Graph::parse_directed("a: b\nb: c\nc: b d\n").unwrap();
+        let err = cyclic.count_paths(0, 3, &[]).unwrap_err();
+        assert_eq!("graph contains a cycle", err.to_string());
+        let looped = Graph::parse_directed("a: b\nb: a\n").unwrap();
+        assert!(looped.topological_order(0).is_err());
+    }
+
+    #[test]
+    fn long_chains_dont_overflow() {
+        let mut graph = Graph::directed();
+        for i in 0..200_000 {
+            graph.add_edge(i, i + 1);
+        }
+        assert_eq!(1, graph.count_paths(0, 200_000, &[100_000]).unwrap());
+    }
+
+    #[test]
+    fn undirected_edges_go_both_ways() {
+        let mut graph = Graph::undirected();
+        let (a, b) = graph.add_edge('a', 'b');
+        assert_eq!(&[b], graph.successors(a));
+        assert_eq!(&[a], graph.successors(b));
+        assert_eq!(&[a], graph.predecessors(b));
+
+        let graph = Graph::parse_undirected("a: b c\n").unwrap();
+        let [a, b, c] = ["a", "b", "c"].map(|name| graph.node(name).unwrap());
+        assert_eq!(&[a], graph.successors(c));
+        assert_eq!(&[b, c], graph.predecessors(a));
+    }
+}
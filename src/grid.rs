@@ -0,0 +1,324 @@
+//! A rectangular grid of cells, as found in many puzzle inputs.
+//!
+//! Positions are `(row, column)` pairs, with `(0, 0)` in the top left corner, so that they
+//! line up with the line and column numbers of the input.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{PuzzleError, Result};
+
+/// A position in a grid, as `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// An offset between positions, as `(rows, columns)`.
+pub type Delta = (isize, isize);
+
+/// The offsets to the orthogonal neighbors of a cell: up, right, down and left.
+pub const ORTHOGONAL: [Delta; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The offsets to all eight neighbors of a cell, row by row.
+pub const ADJACENT: [Delta; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells isn't a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            cells.len().is_multiple_of(width),
+            "{} cells can't form rows of {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// Parses a grid with one row per line, converting characters with `cell`. Returns an
+    /// error pointing at the first character `cell` returns `None` for, or at the first line
+    /// whose length differs from the first line's. Like other parse errors, columns are byte
+    /// offsets.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        for (i, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (j, c) in line.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| PuzzleError::at(i, j, format!("invalid character '{c}'")))?;
+                cells.push(value);
+            }
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(PuzzleError::at_line(
+                        i,
+                        format!("expected {width} columns, got {len}"),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Grid::from_cells(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position `delta` away from `pos`, or `None` if it is outside the grid.
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): Delta) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Returns the position `delta` away from `pos`, wrapping around the edges of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty, since there is no position to wrap to.
+    pub fn wrapping_offset(&self, (row, col): Pos, (d_row, d_col): Delta) -> Pos {
+        assert!(
+            self.width > 0 && self.height > 0,
+            "can't wrap around the empty {}x{} grid",
+            self.width,
+            self.height
+        );
+        let wrap =
+            |x: usize, d: isize, len: usize| (x as isize + d).rem_euclid(len as isize) as usize;
+        (wrap(row, d_row, self.height), wrap(col, d_col, self.width))
+    }
+
+    /// Returns the position `delta` away from `pos`, stopping at the edges of the grid.
+    pub fn clamped_offset(&self, (row, col): Pos, (d_row, d_col): Delta) -> Pos {
+        let clamp =
+            |x: usize, d: isize, len: usize| x.saturating_add_signed(d).min(len.saturating_sub(1));
+        (
+            clamp(row, d_row, self.height),
+            clamp(col, d_col, self.width),
+        )
+    }
+
+    /// Returns the positions of the orthogonal neighbors of `pos` inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Returns the positions of the orthogonal and diagonal neighbors of `pos` inside the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Returns every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// Returns every position in the grid with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on a width of 0, which only empty grids have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {col} is outside the grid");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Returns the grid flipped over its diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+        // Not `from_cells`, which can't tell how many rows an empty grid has.
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Returns the position of the first cell, row by row, for which `predicate` is true.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i / self.width, i % self.width))
+    }
+
+    /// Returns the position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns a grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(
+            self.contains((row, col)),
+            "({row}, {col}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(
+            self.contains((row, col)),
+            "({row}, {col}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+/// Writes the grid back as text, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse("#.#\n..#\n", Some).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some((0, 1)), grid.find(&'.'));
+        assert_eq!("#.#\n..#\n", grid.to_string());
+        assert_eq!("#.\n..\n##\n", grid.transpose().to_string());
+        assert_eq!(vec!['#', '#'], grid.column(2).copied().collect::<Vec<_>>());
+
+        let err = Grid::parse("#.\n#x\n", |c| (c != 'x').then_some(c)).unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line, err.column));
+        let err = Grid::parse("é#x\n", |c| (c != 'x').then_some(c)).unwrap_err();
+        assert_eq!(Some(4), err.column);
+        let err = Grid::parse("#.\n#\n", Some).unwrap_err();
+        assert_eq!("line 2: expected 2 columns, got 1", err.to_string());
+        assert_eq!(0, Grid::parse("", Some).unwrap().height());
+
+        let empty: Grid<char> = Grid::new(3, 0, '.');
+        let transposed = empty.transpose();
+        assert_eq!((0, 3), (transposed.width(), transposed.height()));
+    }
+
+    #[test]
+    fn neighbors_and_offsets() {
+        let grid = Grid::new(3, 2, 0);
+        let mut neighbors: Vec<Pos> = grid.neighbors4((0, 0)).collect();
+        neighbors.sort();
+        assert_eq!(vec![(0, 1), (1, 0)], neighbors);
+        assert_eq!(5, grid.neighbors8((1, 1)).count());
+        assert_eq!(None, grid.offset((0, 0), (-1, 0)));
+        assert_eq!((1, 2), grid.wrapping_offset((0, 0), (-1, -1)));
+        assert_eq!((1, 0), grid.clamped_offset((0, 0), (5, -5)));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't wrap around the empty 3x0 grid")]
+    fn wrapping_offset_on_empty_grid() {
+        Grid::new(3, 0, 0).wrapping_offset((0, 0), (1, 1));
+    }
+}
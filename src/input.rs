@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+use crate::debug;
+
+/// How a puzzle's input is cleaned up before it reaches [`Solution::parse`](crate::Solution::parse).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    /// Strip a byte order mark, convert CRLF line endings to LF and remove trailing blank
+    /// lines.
+    pub clean: bool,
+    /// Pad every line with spaces to the length of the longest, for grids whose trailing
+    /// spaces were trimmed by an editor.
+    pub pad_lines: bool,
+}
+
+impl Normalize {
+    /// Cleans up line endings and trailing blank lines.
+    pub const DEFAULT: Normalize = Normalize {
+        clean: true,
+        pad_lines: false,
+    };
+
+    /// Like [`Normalize::DEFAULT`], and also pads ragged lines.
+    pub const GRID: Normalize = Normalize {
+        clean: true,
+        pad_lines: true,
+    };
+
+    /// Leaves the input untouched, for puzzles where whitespace is significant.
+    pub const RAW: Normalize = Normalize {
+        clean: false,
+        pad_lines: false,
+    };
+}
+
+/// Strips trailing blank lines, keeping the newline which ends the last non-blank line.
+fn strip_trailing_blank_lines(input: &str) -> &str {
+    let content = input.trim_end().len();
+    match input[content..].find('\n') {
+        Some(newline) => &input[..content + newline + 1],
+        None => input,
+    }
+}
+
+/// Returns the number of blank lines at the end of an input, which cleaning it removes.
+pub fn trailing_blank_lines(input: &str) -> usize {
+    input[strip_trailing_blank_lines(input).len()..]
+        .lines()
+        .count()
+}
+
+/// Pads lines with spaces so they all have as many characters as the longest.
+fn pad_lines(input: &str) -> Option<String> {
+    let widths: Vec<usize> = input.lines().map(|line| line.chars().count()).collect();
+    let width = widths.iter().copied().max()?;
+    if widths.iter().all(|&w| w == width) {
+        return None;
+    }
+    let mut padded = String::with_capacity(width * widths.len() + widths.len());
+    for (line, w) in input.lines().zip(widths) {
+        padded.push_str(line);
+        padded.extend(std::iter::repeat_n(' ', width - w));
+        padded.push('\n');
+    }
+    if !input.ends_with('\n') {
+        padded.pop();
+    }
+    Some(padded)
+}
+
+/// Applies `options` to an input, only copying it if something changed.
+pub fn normalize(input: &str, options: Normalize) -> Cow<'_, str> {
+    let mut input = Cow::Borrowed(input);
+    if options.clean {
+        if let Some(stripped) = input.strip_prefix('\u{feff}') {
+            input = Cow::Owned(stripped.to_string());
+        }
+        if input.contains("\r\n") {
+            input = Cow::Owned(input.replace("\r\n", "\n"));
+        }
+        let stripped = strip_trailing_blank_lines(&input);
+        if stripped.len() < input.len() {
+            let blank = input[stripped.len()..].lines().count();
+            debug!("ignoring {blank} trailing blank line(s)");
+            input = Cow::Owned(stripped.to_string());
+        }
+    }
+    if options.pad_lines
+        && let Some(padded) = pad_lines(&input)
+    {
+        input = Cow::Owned(padded);
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cleans_line_endings_and_trailing_blank_lines() {
+        let input = "\u{feff}a b\r\n c \r\n\r\n  \n";
+        assert_eq!("a b\n c \n", normalize(input, Normalize::DEFAULT));
+        assert_eq!(input, normalize(input, Normalize::RAW));
+        assert_eq!(2, trailing_blank_lines(input));
+        assert_eq!(0, trailing_blank_lines("a\n"));
+        assert!(matches!(
+            normalize("a\nb\n", Normalize::DEFAULT),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn pads_ragged_lines() {
+        assert_eq!(
+            "12 \n3  \n+ *\n",
+            normalize("12\n3\n+ *\n", Normalize::GRID)
+        );
+        assert_eq!("ab\nc ", normalize("ab\nc", Normalize::GRID));
+        assert_eq!("", normalize("", Normalize::GRID));
+    }
+}
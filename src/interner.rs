@@ -0,0 +1,85 @@
+//! Dense ids for arbitrary keys, so that structures indexed by position can hold them.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Assigns each distinct key an id, counting up from 0 in the order keys are first seen.
+#[derive(Debug, Clone)]
+pub struct Interner<K> {
+    ids: HashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K> Default for Interner<K> {
+    fn default() -> Interner<K> {
+        Interner {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> Interner<K> {
+    pub fn new() -> Interner<K> {
+        Interner::default()
+    }
+
+    /// Returns the id of `key`, assigning it the next id if it hasn't been seen before.
+    pub fn intern(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    /// Returns the id of `key`, if it has been interned.
+    pub fn get<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(key).copied()
+    }
+
+    /// Returns the key with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no key has that id.
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    /// Returns every key, in order of id.
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn ids_are_dense_and_stable() {
+        let mut interner = Interner::new();
+        assert_eq!(0, interner.intern("you".to_string()));
+        assert_eq!(1, interner.intern("out".to_string()));
+        assert_eq!(0, interner.intern("you".to_string()));
+        assert_eq!(Some(1), interner.get("out"));
+        assert_eq!(None, interner.get("svr"));
+        assert_eq!("out", interner.key(1));
+        assert_eq!(2, interner.len());
+    }
+}
@@ -1,24 +1,58 @@
-use std::fmt::Display;
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod days;
+mod error;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+mod input;
+pub mod interner;
+mod puzzle;
+mod rng;
+pub mod site;
+pub mod submissions;
+pub mod trace;
+pub mod union_find;
+
+pub use answer::Answer;
+pub use error::{ErrorKind, PuzzleError, Result, column_of, parse_at};
+pub use input::{Normalize, normalize, trailing_blank_lines};
+pub use puzzle::{Day, ParseIdError, PuzzleId, Selection, Year};
+pub use rng::Rng;
 
 pub trait Solution<'a> {
-    type Output: Display + 'a;
-    fn part1(input: &'a str) -> Self::Output;
-    fn part2(input: &'a str) -> Self::Output;
-}
+    /// Representation of the input shared by both parts.
+    type Parsed: 'a;
+    type Output1: Into<Answer> + 'a;
+    /// Output of part 2. Puzzles without a second part use [`std::convert::Infallible`].
+    type Output2: Into<Answer> + 'a;
 
-// Split a string into exactly `N` parts.
-//
-// # Panics
-//
-// Panics if the string cannoy be split into exactly `N` parts.
-pub fn splitn<const N: usize>(s: &str, delim: char) -> [&str; N] {
-    let mut parts = s.split(delim);
-    let mut arr = [""; N];
-    for (i, elem) in arr.iter_mut().enumerate() {
-        *elem = parts.next().unwrap_or_else(|| panic!("expected {N} parts, got {i}"));
-    }
-    if parts.next().is_some() {
-        panic!("expected {N} parts, got more");
+    /// Number of parts the puzzle has, either 1 or 2.
+    ///
+    /// Puzzles with a single part (like the last day of each year) set this to 1 and don't
+    /// implement [`part2`](Solution::part2).
+    const PARTS: u8 = 2;
+
+    /// How the input is cleaned up before [`parse`](Solution::parse) sees it. Puzzles where
+    /// whitespace is significant use [`Normalize::RAW`].
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+    fn parse(input: &'a str) -> Result<Self::Parsed>;
+    fn part1(input: &Self::Parsed) -> Result<Self::Output1>;
+
+    fn part2(_input: &Self::Parsed) -> Result<Self::Output2> {
+        Err(PuzzleError::new("puzzle has no part 2"))
     }
-    arr
+}
+
+/// Generates random valid inputs for a puzzle, for cross-checking and stress-testing
+/// solutions.
+///
+/// Implemented by a day's `Problem` type, which `build.rs` then registers as the day's
+/// generator.
+pub trait InputGenerator {
+    /// Generates an input from `rng`. `size` scales the input, for example the number of lines,
+    /// so that small inputs stay cheap for slow reference solutions.
+    fn generate(rng: &mut Rng, size: usize) -> String;
 }
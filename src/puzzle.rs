@@ -0,0 +1,259 @@
+//! Identifiers of puzzles, and selections of several puzzles.
+//!
+//! Puzzles are identified by the date they were released, `YYYY-12-DD`, which is also how they
+//! are named in `inputs/` and the answers file.
+
+use std::{fmt, str::FromStr};
+
+/// Error from parsing a [`Year`], [`Day`], [`PuzzleId`] or [`Selection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError(String);
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ParseIdError {}
+
+/// Year of an Advent of Code event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The first event.
+    pub const FIRST: Year = Year(2015);
+
+    /// # Panics
+    ///
+    /// Panics if `year` is before the first event.
+    pub const fn new(year: u16) -> Year {
+        assert!(
+            year >= Year::FIRST.0,
+            "there was no Advent of Code before 2015"
+        );
+        Year(year)
+    }
+
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    /// Number of puzzles released in the event: 25 until 2024, 12 since.
+    pub const fn days(self) -> u8 {
+        if self.0 < 2025 { 25 } else { 12 }
+    }
+}
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Year, ParseIdError> {
+        match s.parse() {
+            Ok(year) if s.len() == 4 && year >= Year::FIRST.0 => Ok(Year(year)),
+            _ => Err(ParseIdError(format!("invalid year {s}"))),
+        }
+    }
+}
+
+/// Day of December on which a puzzle was released, from 1 to 25.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(u8);
+
+impl Day {
+    /// # Panics
+    ///
+    /// Panics if `day` isn't between 1 and 25.
+    pub const fn new(day: u8) -> Day {
+        assert!(day >= 1 && day <= 25, "days must be between 1 and 25");
+        Day(day)
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+impl FromStr for Day {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Day, ParseIdError> {
+        match s.parse() {
+            Ok(day) if s.len() == 2 && (1..=25).contains(&day) => Ok(Day(day)),
+            _ => Err(ParseIdError(format!("invalid day {s}"))),
+        }
+    }
+}
+
+/// Identifies a single puzzle. Formatted as the date it was released, `YYYY-12-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// # Panics
+    ///
+    /// Panics if there was no puzzle on the given day.
+    pub const fn new(year: u16, day: u8) -> PuzzleId {
+        let year = Year::new(year);
+        assert!(day <= year.days(), "there was no puzzle on that day");
+        PuzzleId {
+            year,
+            day: Day::new(day),
+        }
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-12-{}", self.year, self.day)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<PuzzleId, ParseIdError> {
+        let invalid = || ParseIdError(format!("invalid date {s}, expected YYYY-12-DD"));
+        let (year, day) = s
+            .split_once("-12-")
+            .filter(|(year, _)| year.len() == 4)
+            .ok_or_else(invalid)?;
+        let year: Year = year.parse()?;
+        let day: Day = day.parse()?;
+        if day.0 > year.days() {
+            return Err(ParseIdError(format!(
+                "{year} only had {} puzzles",
+                year.days()
+            )));
+        }
+        Ok(PuzzleId { year, day })
+    }
+}
+
+/// A set of puzzles: a whole year (`2024`), a single puzzle (`2025-12-01`), or a range of days
+/// in one year (`2025-12-01..05` or `2025-12-01..2025-12-05`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    Year(Year),
+    Days { first: PuzzleId, last: PuzzleId },
+}
+
+impl Selection {
+    pub fn contains(&self, id: PuzzleId) -> bool {
+        match *self {
+            Selection::Year(year) => id.year == year,
+            Selection::Days { first, last } => (first..=last).contains(&id),
+        }
+    }
+
+    /// Returns the puzzle if exactly one is selected.
+    pub fn single(&self) -> Option<PuzzleId> {
+        match *self {
+            Selection::Days { first, last } if first == last => Some(first),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selection::Year(year) => write!(f, "{year}"),
+            Selection::Days { first, last } if first == last => write!(f, "{first}"),
+            Selection::Days { first, last } => write!(f, "{first}..{}", last.day),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Selection, ParseIdError> {
+        if s.len() == 4 {
+            return s.parse().map(Selection::Year);
+        }
+        let Some((first, last)) = s.split_once("..") else {
+            let id = s.parse()?;
+            return Ok(Selection::Days {
+                first: id,
+                last: id,
+            });
+        };
+        let first: PuzzleId = first.parse()?;
+        let last = if last.len() == 2 {
+            format!("{}-12-{last}", first.year)
+        } else {
+            last.to_owned()
+        };
+        let last: PuzzleId = last.parse()?;
+        if last.year != first.year || last < first {
+            return Err(ParseIdError(format!(
+                "invalid range {s}, expected days in one year in increasing order"
+            )));
+        }
+        Ok(Selection::Days { first, last })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_puzzle_ids() {
+        let id: PuzzleId = "2025-12-01".parse().unwrap();
+        assert_eq!(PuzzleId::new(2025, 1), id);
+        assert_eq!("2025-12-01", id.to_string());
+        assert_eq!(Ok(PuzzleId::new(2024, 25)), "2024-12-25".parse());
+        for s in [
+            "2025-12-13",
+            "2014-12-01",
+            "2025-11-01",
+            "2025-12-1",
+            "25-12-01",
+        ] {
+            assert!(s.parse::<PuzzleId>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn parse_selections() {
+        let range = Selection::Days {
+            first: PuzzleId::new(2025, 1),
+            last: PuzzleId::new(2025, 5),
+        };
+        assert_eq!(Ok(range), "2025-12-01..05".parse());
+        assert_eq!(Ok(range), "2025-12-01..2025-12-05".parse());
+        assert_eq!("2025-12-01..05", range.to_string());
+        assert!(range.contains(PuzzleId::new(2025, 5)));
+        assert!(!range.contains(PuzzleId::new(2025, 6)));
+        assert_eq!(None, range.single());
+
+        let year: Selection = "2024".parse().unwrap();
+        assert!(year.contains(PuzzleId::new(2024, 25)));
+        assert!(!year.contains(PuzzleId::new(2025, 1)));
+
+        let single: Selection = "2025-12-03".parse().unwrap();
+        assert_eq!(Some(PuzzleId::new(2025, 3)), single.single());
+
+        assert!("2025-12-05..01".parse::<Selection>().is_err());
+        assert!("2024-12-01..2025-12-01".parse::<Selection>().is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use std::ops::Range;
+
+/// A small seeded pseudo-random number generator (SplitMix64), so that generated inputs can be
+/// reproduced from their seed. Not suitable for anything needing real randomness.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "cannot pick from an empty range");
+        let len = range.end - range.start;
+        // Multiply-shift reduction, whose bias is negligible for the ranges used here.
+        let scaled = (u128::from(self.next_u64()) * u128::from(len)) >> 64;
+        range.start + scaled as u64
+    }
+
+    /// Returns an index in `0..len`, which must not be empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..len as u64) as usize
+    }
+
+    /// Shuffles `items` in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// Returns `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let numbers: Vec<u64> = (0..100).map(|_| a.range(10..20)).collect();
+        assert_eq!(
+            numbers,
+            (0..100).map(|_| b.range(10..20)).collect::<Vec<_>>()
+        );
+        assert!(numbers.iter().all(|n| (10..20).contains(n)));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+}
@@ -0,0 +1,479 @@
+//! Talking to the Advent of Code website: downloading inputs and submitting answers.
+//!
+//! Inputs and answers are personal, so requests are authenticated with the session cookie of a
+//! logged-in browser. The token is read from the `AOC_SESSION` environment variable, or else
+//! from `~/.config/aoc/session`. Downloaded inputs are cached under `inputs/` and never fetched
+//! again.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{Answer, PuzzleId};
+
+/// Default base URL of the Advent of Code website.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Makes HTTP requests on behalf of a [`Site`].
+pub trait HttpClient {
+    /// Sends a `GET` request with the given cookie and returns the response body. Responses
+    /// other than `200 OK` are errors.
+    fn get(&self, url: &str, cookie: &str) -> io::Result<String>;
+
+    /// Sends a `POST` request with the given cookie and URL-encoded form, and returns the
+    /// response body. Responses other than `200 OK` are errors.
+    fn post(&self, url: &str, cookie: &str, form: &[(&str, &str)]) -> io::Result<String>;
+}
+
+/// The default [`HttpClient`], backed by `ureq`.
+#[derive(Debug, Clone)]
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> UreqClient {
+        UreqClient {
+            agent: ureq::Agent::new_with_defaults(),
+        }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, cookie: &str) -> io::Result<String> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", cookie)
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|err| io::Error::other(format!("{url}: {err}")))?;
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| io::Error::other(format!("{url}: {err}")))
+    }
+
+    fn post(&self, url: &str, cookie: &str, form: &[(&str, &str)]) -> io::Result<String> {
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", cookie)
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .map_err(|err| io::Error::other(format!("{url}: {err}")))?;
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| io::Error::other(format!("{url}: {err}")))
+    }
+}
+
+/// Path of the session token file, `~/.config/aoc/session`, honouring `XDG_CONFIG_HOME`.
+pub fn session_path() -> Option<PathBuf> {
+    let config = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(config.join("aoc/session"))
+}
+
+/// Returns the session token from the environment or the session file, if either is set.
+pub fn session_token() -> Option<String> {
+    let token = match env::var(SESSION_VAR) {
+        Ok(token) => token,
+        Err(_) => fs::read_to_string(session_path()?).ok()?,
+    };
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_owned())
+}
+
+/// Returns the path `inputs/<date>.txt` in which the input for a puzzle is cached.
+pub fn input_path(inputs_dir: impl AsRef<Path>, id: PuzzleId) -> PathBuf {
+    inputs_dir.as_ref().join(format!("{id}.txt"))
+}
+
+/// The website's judgement of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// Not judged, because the previous answer was submitted too recently.
+    TooSoon,
+    /// Not judged, because the part was already solved or isn't unlocked yet.
+    WrongLevel,
+}
+
+/// The response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How long to wait before submitting another answer, if the website said.
+    pub wait: Option<Duration>,
+    /// The text of the response, without markup.
+    pub message: String,
+}
+
+/// Returns the text of the first `<article>` of a page, with tags removed.
+fn article_text(html: &str) -> Option<String> {
+    let start = html.find("<article")?;
+    let end = start + html[start..].find("</article>")?;
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Parses a wait such as `one minute`, `5 minutes` or `1m 30s` starting at the beginning of
+/// `s`.
+fn parse_wait(s: &str) -> Option<Duration> {
+    let mut words = s.split_whitespace();
+    let first = words.next()?;
+    if let Some(n) = ["one", "two", "three", "four", "five", "ten"]
+        .iter()
+        .zip([1, 2, 3, 4, 5, 10])
+        .find_map(|(word, n)| (*word == first).then_some(n))
+        .or_else(|| first.parse().ok())
+    {
+        return match words.next()? {
+            unit if unit.starts_with("second") => Some(Duration::from_secs(n)),
+            unit if unit.starts_with("minute") => Some(Duration::from_secs(n * 60)),
+            _ => None,
+        };
+    }
+    // The compact form: `4m 12s`, `37s`.
+    let mut secs = 0;
+    let mut any = false;
+    for word in s.split_whitespace() {
+        let (n, unit) = word.split_at_checked(word.len().checked_sub(1)?)?;
+        let Ok(n) = n.parse::<u64>() else { break };
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => break,
+        };
+        any = true;
+    }
+    any.then(|| Duration::from_secs(secs))
+}
+
+/// Parses the page returned after submitting an answer.
+pub fn parse_response(html: &str) -> io::Result<Response> {
+    let message = article_text(html).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "no <article> in the response")
+    })?;
+    let verdict = if message.starts_with("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if message.starts_with("That's not the right answer") {
+        Verdict::Incorrect
+    } else if message.starts_with("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if message.starts_with("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unrecognized response: {message}"),
+        ));
+    };
+    let lower = message.to_lowercase();
+    let wait = ["you have ", "please wait "].iter().find_map(|marker| {
+        lower
+            .match_indices(marker)
+            .find_map(|(i, _)| parse_wait(&lower[i + marker.len()..]))
+    });
+    Ok(Response {
+        verdict,
+        wait,
+        message,
+    })
+}
+
+/// Client for the website, caching downloaded inputs on disk.
+#[derive(Debug, Clone)]
+pub struct Site<H = UreqClient> {
+    http: H,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Site {
+    /// Creates a client for the real website, or for the one at `AOC_BASE_URL` if it is set,
+    /// using the session token from the environment or the session file.
+    pub fn from_env() -> Site {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        Site::new(UreqClient::default(), base_url, session_token())
+    }
+}
+
+impl<H: HttpClient> Site<H> {
+    pub fn new(http: H, base_url: impl Into<String>, session: Option<String>) -> Site<H> {
+        let base_url = base_url.into().trim_end_matches('/').to_owned();
+        Site {
+            http,
+            base_url,
+            session,
+        }
+    }
+
+    fn cookie(&self) -> io::Result<String> {
+        let session = self.session.as_deref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no session token: set {SESSION_VAR} or write it to ~/.config/aoc/session"),
+            )
+        })?;
+        Ok(format!("session={session}"))
+    }
+
+    /// Returns the URL of the page of the given puzzle, with `path` appended.
+    fn puzzle_url(&self, id: PuzzleId, path: &str) -> String {
+        let (year, day) = (id.year.get(), id.day.get());
+        format!("{}/{year}/day/{day}{path}", self.base_url)
+    }
+
+    /// Downloads the input for a puzzle, without looking at the cache.
+    pub fn download(&self, id: PuzzleId) -> io::Result<String> {
+        let url = self.puzzle_url(id, "/input");
+        self.http.get(&url, &self.cookie()?)
+    }
+
+    /// Returns the path of the cached input for a puzzle in `inputs_dir`, downloading it first
+    /// if it isn't cached yet. An empty file, as left by `aoc new`, doesn't count as cached.
+    /// The second value is `true` if the input was downloaded.
+    pub fn fetch(&self, inputs_dir: impl AsRef<Path>, id: PuzzleId) -> io::Result<(PathBuf, bool)> {
+        let inputs_dir = inputs_dir.as_ref();
+        let path = input_path(inputs_dir, id);
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok((path, false));
+        }
+        let input = self.download(id)?;
+        // Write to a temporary file first, so an interrupted write never leaves a truncated
+        // input in the cache.
+        fs::create_dir_all(inputs_dir)?;
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+        Ok((path, true))
+    }
+
+    /// Submits an answer to the given part and returns the website's response.
+    pub fn submit(&self, id: PuzzleId, part: u8, answer: &Answer) -> io::Result<Response> {
+        let url = self.puzzle_url(id, "/answer");
+        let level = part.to_string();
+        let answer = answer.to_string();
+        let html = self.http.post(
+            &url,
+            &self.cookie()?,
+            &[("level", &level), ("answer", &answer)],
+        )?;
+        parse_response(&html)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Starts a stand-in server that answers each request with the given status and body, and
+    /// returns its base URL along with a channel receiving each request.
+    fn serve(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(&stream);
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str("\r\n");
+                request.push_str(&String::from_utf8(content).unwrap());
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                if tx.send(request).is_err() {
+                    break;
+                }
+            }
+        });
+        (base_url, rx)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-site-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetch_downloads_once() {
+        let (base_url, requests) = serve("200 OK", "1\n2\n3\n");
+        let dir = temp_dir("once");
+        let site = Site::new(UreqClient::default(), base_url, Some("abc".into()));
+
+        let (path, downloaded) = site.fetch(&dir, PuzzleId::new(2025, 1)).unwrap();
+        assert!(downloaded);
+        assert_eq!(dir.join("2025-12-01.txt"), path);
+        assert_eq!("1\n2\n3\n", fs::read_to_string(&path).unwrap());
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with("GET /2025/day/1/input HTTP/1.1\r\n"),
+            "{request}"
+        );
+        assert!(
+            request.to_lowercase().contains("cookie: session=abc\r\n"),
+            "{request}"
+        );
+
+        assert_eq!(
+            (path, false),
+            site.fetch(&dir, PuzzleId::new(2025, 1)).unwrap()
+        );
+        assert!(requests.try_recv().is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_replaces_empty_placeholder() {
+        let (base_url, _requests) = serve("200 OK", "1\n2\n3\n");
+        let dir = temp_dir("placeholder");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input_path(&dir, PuzzleId::new(2025, 1)), "").unwrap();
+        let site = Site::new(UreqClient::default(), base_url, Some("abc".into()));
+        let (path, downloaded) = site.fetch(&dir, PuzzleId::new(2025, 1)).unwrap();
+        assert!(downloaded);
+        assert_eq!("1\n2\n3\n", fs::read_to_string(&path).unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let (base_url, _requests) = serve(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        );
+        let dir = temp_dir("failed");
+        let site = Site::new(UreqClient::default(), base_url, Some("abc".into()));
+        assert!(site.fetch(&dir, PuzzleId::new(2025, 12)).is_err());
+        assert!(!input_path(&dir, PuzzleId::new(2025, 12)).exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn download_requires_session() {
+        let site = Site::new(UreqClient::default(), "http://127.0.0.1:9", None);
+        let err = site.download(PuzzleId::new(2025, 1)).unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, err.kind());
+    }
+
+    #[test]
+    fn submit_posts_answer() {
+        let (base_url, requests) = serve(
+            "200 OK",
+            "<main>\n<article><p>That's not the right answer; your answer is too high.  If \
+             you're stuck, make sure you're using the full input data.  Please wait one minute \
+             before trying again. <a href=\"/2025/day/8\">[Return to Day 8]</a></p></article>\n\
+             </main>",
+        );
+        let site = Site::new(UreqClient::default(), base_url, Some("abc".into()));
+        let response = site
+            .submit(PuzzleId::new(2025, 8), 2, &Answer::Int(1234))
+            .unwrap();
+        assert_eq!(Verdict::TooHigh, response.verdict);
+        assert_eq!(Some(Duration::from_secs(60)), response.wait);
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with("POST /2025/day/8/answer HTTP/1.1\r\n"),
+            "{request}"
+        );
+        assert!(
+            request.ends_with("\r\n\r\nlevel=2&answer=1234"),
+            "{request}"
+        );
+    }
+
+    #[test]
+    fn parse_responses() {
+        let article = |text: &str| format!("<html><article><p>{text}</p></article></html>");
+        let parse = |text: &str| {
+            let response = parse_response(&article(text)).unwrap();
+            (response.verdict, response.wait.map(|wait| wait.as_secs()))
+        };
+        assert_eq!(
+            (Verdict::Correct, None),
+            parse("That's the right answer!  You are <em>one gold star</em> closer.")
+        );
+        assert_eq!(
+            (Verdict::TooLow, Some(300)),
+            parse(
+                "That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again."
+            )
+        );
+        assert_eq!(
+            (Verdict::Incorrect, Some(60)),
+            parse("That's not the right answer.  Please wait one minute before trying again.")
+        );
+        assert_eq!(
+            (Verdict::TooSoon, Some(252)),
+            parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait."
+            )
+        );
+        assert_eq!(
+            (Verdict::WrongLevel, None),
+            parse("You don't seem to be solving the right level.  Did you already complete it?")
+        );
+        assert!(parse_response(&article("Something else")).is_err());
+    }
+}
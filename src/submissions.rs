@@ -0,0 +1,249 @@
+//! The local history of submitted answers, `submissions.json`.
+//!
+//! Every submission and the website's verdict on it is kept, so that known-wrong answers, and
+//! answers outside the bounds learnt from "too high" and "too low" verdicts, are never
+//! submitted again. The end of the website's cooldown after a wrong answer is kept too.
+
+use std::{fs, io, path::Path, time::SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Answer, PuzzleId,
+    site::{Response, Verdict},
+};
+
+/// Default location of the submission history, relative to the repository root.
+pub const DEFAULT_PATH: &str = "submissions.json";
+
+/// Returns the current time in seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub date: String,
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the Unix epoch at which the answer was submitted.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+/// Why an answer shouldn't be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with the given answer.
+    Solved(String),
+    /// The answer was already submitted and judged wrong.
+    AlreadyWrong(Verdict),
+    /// The answer is at least a previous answer that was too high.
+    AboveBound(i128),
+    /// The answer is at most a previous answer that was too low.
+    BelowBound(i128),
+    /// The website won't judge answers for this many more seconds.
+    Cooldown(u64),
+}
+
+impl std::fmt::Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "already solved with {answer}"),
+            Refusal::AlreadyWrong(verdict) => {
+                let judged = match verdict {
+                    Verdict::TooHigh => "too high",
+                    Verdict::TooLow => "too low",
+                    _ => "wrong",
+                };
+                write!(f, "already submitted and judged {judged}")
+            }
+            Refusal::AboveBound(bound) => write!(f, "{bound} was already too high"),
+            Refusal::BelowBound(bound) => write!(f, "{bound} was already too low"),
+            Refusal::Cooldown(secs) => write!(f, "wait {secs}s before submitting again"),
+        }
+    }
+}
+
+/// Everything submitted so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submissions {
+    /// Seconds since the Unix epoch until which the website won't judge answers.
+    #[serde(default)]
+    pub cooldown_until: u64,
+    #[serde(default)]
+    pub submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Reads the submission history at `path`. A missing file is treated as an empty history.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Submissions> {
+        match fs::read_to_string(path) {
+            Ok(s) => Ok(serde_json::from_str(&s)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the submission history to `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        fs::write(path, json)
+    }
+
+    fn of(&self, id: PuzzleId, part: u8) -> impl Iterator<Item = &Submission> {
+        let date = id.to_string();
+        self.submissions
+            .iter()
+            .filter(move |s| s.date == date && s.part == part)
+    }
+
+    /// Returns whether an answer to the given part was accepted.
+    pub fn solved(&self, id: PuzzleId, part: u8) -> bool {
+        self.of(id, part).any(|s| s.verdict == Verdict::Correct)
+    }
+
+    /// Returns the puzzles with at least one submission.
+    pub fn puzzles(&self) -> impl Iterator<Item = PuzzleId> {
+        self.submissions.iter().filter_map(|s| s.date.parse().ok())
+    }
+
+    /// Returns the highest answer known to be too low and the lowest answer known to be too
+    /// high for the given part.
+    pub fn bounds(&self, id: PuzzleId, part: u8) -> (Option<i128>, Option<i128>) {
+        let mut low = None;
+        let mut high = None;
+        for submission in self.of(id, part) {
+            let Ok(answer) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+            match submission.verdict {
+                Verdict::TooLow => low = low.max(Some(answer)),
+                Verdict::TooHigh => high = Some(high.map_or(answer, |high: i128| high.min(answer))),
+                _ => {}
+            }
+        }
+        (low, high)
+    }
+
+    /// Checks whether `answer` is worth submitting for the given part at time `now`.
+    pub fn check(&self, id: PuzzleId, part: u8, answer: &Answer, now: u64) -> Result<(), Refusal> {
+        let text = answer.to_string();
+        for submission in self.of(id, part) {
+            match submission.verdict {
+                Verdict::Correct => return Err(Refusal::Solved(submission.answer.clone())),
+                Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+                    if submission.answer == text =>
+                {
+                    return Err(Refusal::AlreadyWrong(submission.verdict));
+                }
+                _ => {}
+            }
+        }
+        if let Answer::Int(n) = *answer {
+            match self.bounds(id, part) {
+                (_, Some(high)) if n >= high => return Err(Refusal::AboveBound(high)),
+                (Some(low), _) if n <= low => return Err(Refusal::BelowBound(low)),
+                _ => {}
+            }
+        }
+        if now < self.cooldown_until {
+            return Err(Refusal::Cooldown(self.cooldown_until - now));
+        }
+        Ok(())
+    }
+
+    /// Records the response to submitting `answer` at time `now`.
+    pub fn record(
+        &mut self,
+        id: PuzzleId,
+        part: u8,
+        answer: &Answer,
+        now: u64,
+        response: &Response,
+    ) {
+        if let Some(wait) = response.wait {
+            self.cooldown_until = now + wait.as_secs();
+        }
+        self.submissions.push(Submission {
+            date: id.to_string(),
+            part,
+            answer: answer.to_string(),
+            timestamp: now,
+            verdict: response.verdict,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    fn response(verdict: Verdict, wait: Option<u64>) -> Response {
+        Response {
+            verdict,
+            wait: wait.map(Duration::from_secs),
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut submissions = Submissions::default();
+        let id = PuzzleId::new(2025, 8);
+        submissions.record(
+            id,
+            1,
+            &Answer::Int(100),
+            0,
+            &response(Verdict::TooLow, Some(60)),
+        );
+        submissions.record(
+            id,
+            1,
+            &Answer::Int(500),
+            60,
+            &response(Verdict::TooHigh, Some(60)),
+        );
+        submissions.record(
+            id,
+            1,
+            &Answer::Int(300),
+            120,
+            &response(Verdict::TooHigh, Some(300)),
+        );
+        assert_eq!((Some(100), Some(300)), submissions.bounds(id, 1));
+        assert_eq!((None, None), submissions.bounds(id, 2));
+
+        let check = |n, now| submissions.check(id, 1, &Answer::Int(n), now);
+        assert_eq!(
+            Err(Refusal::AlreadyWrong(Verdict::TooHigh)),
+            check(300, 1000)
+        );
+        assert_eq!(Err(Refusal::AboveBound(300)), check(400, 1000));
+        assert_eq!(Err(Refusal::BelowBound(100)), check(50, 1000));
+        assert_eq!(Err(Refusal::Cooldown(20)), check(200, 400));
+        assert_eq!(Ok(()), check(200, 420));
+
+        submissions.record(
+            id,
+            1,
+            &Answer::Int(200),
+            420,
+            &response(Verdict::Correct, None),
+        );
+        assert!(submissions.solved(id, 1));
+        assert!(!submissions.solved(id, 2));
+        assert_eq!(
+            Err(Refusal::Solved("200".into())),
+            submissions.check(id, 1, &Answer::Int(200), 1000)
+        );
+        assert_eq!(Ok(()), submissions.check(id, 2, &Answer::Int(200), 1000));
+    }
+}
@@ -0,0 +1,136 @@
+//! Leveled diagnostics for solutions, written to standard error or a log file so they never
+//! mix with answers.
+//!
+//! Use the [`warn!`](crate::warn!), [`info!`](crate::info!), [`debug!`](crate::debug!) and
+//! [`trace!`](crate::trace!) macros. Only warnings are shown by default; runners raise the
+//! level with [`set_level`]. Messages below the current level aren't formatted at all, so
+//! they can be left in hot loops.
+
+use std::{
+    fmt::{self, Display},
+    fs::File,
+    io::{self, Write},
+    path::Path,
+    sync::{
+        Mutex,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+/// How important a message is. Each level includes the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Warn = 1,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// Returns the level enabled by passing `-v` `count` times, where no `-v` shows warnings.
+    pub fn from_verbosity(count: u8) -> Level {
+        match count {
+            0 => Level::Warn,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Level::Warn => "warning",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+static LOG_FILE: Mutex<Option<File>> = Mutex::new(None);
+
+/// Shows messages at `level` and below.
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns whether messages at `level` are shown.
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Appends messages to the file at `path` instead of writing them to standard error.
+pub fn set_log_file(path: impl AsRef<Path>) -> io::Result<()> {
+    let file = File::options().create(true).append(true).open(path)?;
+    *LOG_FILE.lock().unwrap() = Some(file);
+    Ok(())
+}
+
+/// Writes a message. Called by the macros once they have checked that `level` is enabled.
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    let module = module.strip_prefix("advent_of_code::").unwrap_or(module);
+    let line = format!("{level}: {module}: {args}\n");
+    // Failing to write a diagnostic isn't worth failing the puzzle for.
+    let mut file = LOG_FILE.lock().unwrap();
+    let _ = match file.as_mut() {
+        Some(file) => file.write_all(line.as_bytes()),
+        None => io::stderr().write_all(line.as_bytes()),
+    };
+}
+
+/// Writes a message at the given [`Level`] if it is enabled.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if $crate::trace::enabled(level) {
+            $crate::trace::write(level, module_path!(), format_args!($($arg)+));
+        }
+    }};
+}
+
+/// Writes a warning, which is shown by default.
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+/// Writes a message shown with `-v`.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Info, $($arg)+) };
+}
+
+/// Writes a message shown with `-vv`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+/// Writes a message shown with `-vvv`, for output too noisy for [`debug!`](crate::debug!).
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn levels_include_lower_levels() {
+        assert_eq!(Level::Warn, Level::from_verbosity(0));
+        assert_eq!(Level::Trace, Level::from_verbosity(5));
+
+        set_level(Level::Info);
+        assert!(enabled(Level::Warn));
+        assert!(enabled(Level::Info));
+        assert!(!enabled(Level::Debug));
+        set_level(Level::Warn);
+    }
+}
@@ -0,0 +1,233 @@
+//! Disjoint sets, for tracking which elements have been connected to each other.
+
+use std::hash::Hash;
+
+use crate::interner::Interner;
+
+/// Partitions the elements `0..len` into disjoint sets, which can be merged.
+///
+/// Sets are merged by size and paths are compressed as they are followed, so that operations
+/// take nearly constant time.
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` elements, each in a set of its own.
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Adds an element in a set of its own, and returns it.
+    pub fn add(&mut self) -> usize {
+        let element = self.parents.len();
+        self.parents.push(element);
+        self.sizes.push(1);
+        self.components += 1;
+        element
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the representative of the set containing `element`, pointing every element on
+    /// the way straight at it.
+    pub fn find(&mut self, element: usize) -> usize {
+        let root = self.root(element);
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Returns the representative of the set containing `element`, without compressing paths.
+    fn root(&self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            element = self.parents[element];
+        }
+        element
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they were already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (small, large) = if self.sizes[a] < self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.components -= 1;
+        true
+    }
+
+    /// Returns whether `a` and `b` are in the same set.
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the number of elements in the set containing `element`.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Returns the elements of each set in increasing order, with sets ordered by their
+    /// smallest element.
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut index_of_root = vec![usize::MAX; self.len()];
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+        for element in 0..self.len() {
+            let root = self.root(element);
+            if index_of_root[root] == usize::MAX {
+                index_of_root[root] = components.len();
+                components.push(Vec::with_capacity(self.sizes[root]));
+            }
+            components[index_of_root[root]].push(element);
+        }
+        components.into_iter()
+    }
+}
+
+/// Disjoint sets of arbitrary keys, which are added as they are first used.
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    keys: Interner<K>,
+    sets: UnionFind,
+}
+
+impl<K> Default for KeyedUnionFind<K> {
+    fn default() -> KeyedUnionFind<K> {
+        KeyedUnionFind {
+            keys: Interner::default(),
+            sets: UnionFind::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> KeyedUnionFind<K> {
+    pub fn new() -> KeyedUnionFind<K> {
+        KeyedUnionFind::default()
+    }
+
+    /// Adds `key` in a set of its own, unless it is already present.
+    pub fn insert(&mut self, key: K) -> usize {
+        let id = self.keys.intern(key);
+        if id == self.sets.len() {
+            self.sets.add();
+        }
+        id
+    }
+
+    /// Merges the sets containing `a` and `b`, adding them first if needed. Returns `false` if
+    /// they were already the same set.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Returns the representative of the set containing `key`, or `None` if it was never added.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let id = self.keys.get(key)?;
+        let root = self.sets.find(id);
+        Some(self.keys.key(root))
+    }
+
+    /// Returns whether `a` and `b` are in the same set. Keys which were never added are only in
+    /// the same set as themselves.
+    pub fn same(&mut self, a: &K, b: &K) -> bool {
+        match (self.keys.get(a), self.keys.get(b)) {
+            (Some(a), Some(b)) => self.sets.same(a, b),
+            _ => a == b,
+        }
+    }
+
+    /// Returns the number of keys in the set containing `key`, which is 1 for keys never added.
+    pub fn component_size(&mut self, key: &K) -> usize {
+        self.keys
+            .get(key)
+            .map_or(1, |id| self.sets.component_size(id))
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// Returns the keys of each set in the order they were added, with sets ordered by their
+    /// first key.
+    pub fn components(&self) -> impl Iterator<Item = Vec<&K>> {
+        self.sets
+            .components()
+            .map(|ids| ids.into_iter().map(|id| self.keys.key(id)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn merges_sets() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 3));
+        assert!(sets.union(4, 3));
+        assert!(!sets.union(0, 4));
+        assert!(sets.union(1, 5));
+        assert_eq!(3, sets.component_count());
+        assert_eq!(3, sets.component_size(4));
+        assert!(sets.same(5, 1));
+        assert!(!sets.same(0, 2));
+        let components: Vec<Vec<usize>> = sets.components().collect();
+        assert_eq!(vec![vec![0, 3, 4], vec![1, 5], vec![2]], components);
+    }
+
+    #[test]
+    fn long_chains_dont_overflow() {
+        let len = 1_000_000;
+        let mut sets = UnionFind::new(len);
+        // Merging by size never builds long chains, so build one by hand.
+        sets.parents = (0..len).map(|i| i.saturating_sub(1)).collect();
+        assert_eq!(0, sets.find(len - 1));
+        assert_eq!(0, sets.parents[len / 2]);
+    }
+
+    #[test]
+    fn keyed_sets() {
+        let mut sets = KeyedUnionFind::new();
+        assert!(sets.union("a", "b"));
+        assert!(sets.union("c", "b"));
+        sets.insert("d");
+        assert_eq!(2, sets.component_count());
+        assert_eq!(3, sets.component_size(&"a"));
+        assert!(sets.same(&"a", &"c"));
+        assert!(!sets.same(&"a", &"e"));
+        assert_eq!(Some(&"a"), sets.find(&"c"));
+        let components: Vec<Vec<&&str>> = sets.components().collect();
+        assert_eq!(vec![vec![&"a", &"b", &"c"], vec![&"d"]], components);
+    }
+}